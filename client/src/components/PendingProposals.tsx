@@ -31,12 +31,12 @@ interface Proposal {
 
 interface SignatureEntry {
   signer_pkh: string;
-  signed_tx_json: string;
+  signed_tx_json?: string; // Withheld by servers that protect tx data with RAW_TX_LINK_SECRET
   signed_at: string;
 }
 
 interface ProposalDetail extends Proposal {
-  raw_tx_json: string;
+  raw_tx_json?: string; // Withheld by servers that protect tx data with RAW_TX_LINK_SECRET
   notes_json: string;
   spend_conditions_json: string;
   participants: string[];
@@ -127,6 +127,11 @@ export default function PendingProposals() {
       // Get full proposal details
       const proposal: ProposalDetail = await apiClient.getProposal(proposalId);
       
+      if (!proposal.raw_tx_json) {
+        setError('This server only shares transaction data through signed links');
+        return;
+      }
+      
       // Reconstruct WASM objects from JSON
       setStatusMessage('Preparing transaction for signing...');
       
@@ -200,6 +205,11 @@ export default function PendingProposals() {
         return;
       }
       
+      if (!proposal.raw_tx_json) {
+        setError('This server only shares transaction data through signed links');
+        return;
+      }
+      
       setStatusMessage('Aggregating signatures...');
       
       // Get the original unsigned transaction (all signers signed this same base)
@@ -210,6 +220,10 @@ export default function PendingProposals() {
       const signedTxProtobufs: any[] = [];
       
       for (const sig of proposal.signatures) {
+        if (!sig.signed_tx_json) {
+          setError('This server only shares transaction data through signed links');
+          return;
+        }
         const signedTx = JSON.parse(sig.signed_tx_json);
         
         // Create a transaction with the unsigned base structure but this signer's witness
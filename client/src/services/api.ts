@@ -140,6 +140,7 @@ class ApiClient {
       const response = await this.client.post(`/api/proposals/${id}/sign`, {
         signer_pkh: signerPkh,
         signed_tx_json: signedTxJson,
+        nonce: Date.now(), // must increase per signer+proposal (replay protection)
       });
       return response.data;
     } catch (error) {
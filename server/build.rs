@@ -0,0 +1,33 @@
+use std::path::Path;
+use std::process::Command;
+
+fn main() {
+    // sqlx::migrate! embeds the migrations directory, which cargo doesn't track on its own
+    println!("cargo:rerun-if-changed=migrations");
+
+    // Docker builds have no .git directory, so the commit can be passed in via GIT_COMMIT
+    println!("cargo:rerun-if-env-changed=GIT_COMMIT");
+    let git_commit = std::env::var("GIT_COMMIT")
+        .ok()
+        .filter(|c| !c.is_empty())
+        .or_else(git_head)
+        .unwrap_or_else(|| "unknown".to_string());
+    for path in ["../.git/HEAD", "../.git/refs/heads", "../.git/packed-refs"] {
+        if Path::new(path).exists() {
+            println!("cargo:rerun-if-changed={}", path);
+        }
+    }
+
+    let built_at = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+    println!("cargo:rustc-env=AGORA_GIT_COMMIT={}", git_commit);
+    println!("cargo:rustc-env=AGORA_BUILD_TIMESTAMP={}", built_at);
+}
+
+fn git_head() -> Option<String> {
+    let output = Command::new("git").args(["rev-parse", "--short=12", "HEAD"]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let commit = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (!commit.is_empty()).then_some(commit)
+}
@@ -0,0 +1,14 @@
+-- ============================================================================
+-- Replay protection for sign requests
+-- ============================================================================
+
+-- Last accepted nonce per signer and proposal; sign requests must present a larger one
+CREATE TABLE IF NOT EXISTS sign_nonces (
+    proposal_id TEXT NOT NULL,
+    signer_pkh TEXT NOT NULL,
+    last_nonce INTEGER NOT NULL,
+    updated_at TEXT NOT NULL,
+    
+    PRIMARY KEY (proposal_id, signer_pkh),
+    FOREIGN KEY (proposal_id) REFERENCES proposals(id) ON DELETE CASCADE
+);
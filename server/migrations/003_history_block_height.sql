@@ -0,0 +1,7 @@
+-- ============================================================================
+-- Block height recorded when a broadcast transaction is confirmed
+-- ============================================================================
+
+ALTER TABLE transaction_history ADD COLUMN block_height INTEGER;
+
+CREATE INDEX IF NOT EXISTS idx_transaction_history_tx_id ON transaction_history(tx_id);
@@ -0,0 +1,6 @@
+-- ============================================================================
+-- Confirmation depth for confirmed transactions
+-- ============================================================================
+
+-- Blocks on top of (and including) the confirming block; refreshed as the chain tip advances
+ALTER TABLE transaction_history ADD COLUMN confirmations INTEGER NOT NULL DEFAULT 0;
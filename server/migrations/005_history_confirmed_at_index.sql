@@ -0,0 +1,5 @@
+-- ============================================================================
+-- Support listing transaction history by confirmation date
+-- ============================================================================
+
+CREATE INDEX IF NOT EXISTS idx_transaction_history_confirmed_at ON transaction_history(confirmed_at);
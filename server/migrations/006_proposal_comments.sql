@@ -0,0 +1,15 @@
+-- ============================================================================
+-- Discussion thread per proposal
+-- ============================================================================
+
+CREATE TABLE IF NOT EXISTS proposal_comments (
+    id TEXT PRIMARY KEY,                    -- UUID
+    proposal_id TEXT NOT NULL,
+    author_pkh TEXT NOT NULL,               -- Must be a participant of the proposal's wallet
+    body TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    
+    FOREIGN KEY (proposal_id) REFERENCES proposals(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_proposal_comments_proposal ON proposal_comments(proposal_id, created_at);
@@ -0,0 +1,25 @@
+-- ============================================================================
+-- Archive for transaction history past the retention window
+-- ============================================================================
+
+CREATE TABLE IF NOT EXISTS archived_history (
+    id TEXT PRIMARY KEY,
+    tx_id TEXT NOT NULL,
+    lock_root_hash TEXT NOT NULL,
+    proposer_pkh TEXT NOT NULL,
+    status TEXT NOT NULL,
+    
+    total_input_nicks INTEGER NOT NULL,
+    seeds_json TEXT NOT NULL,
+    signers_json TEXT NOT NULL,
+    
+    created_at TEXT NOT NULL,
+    broadcast_at TEXT,
+    confirmed_at TEXT,
+    block_height INTEGER,
+    confirmations INTEGER NOT NULL DEFAULT 0,
+    
+    archived_at TEXT NOT NULL               -- When the row was moved out of transaction_history
+);
+
+CREATE INDEX IF NOT EXISTS idx_archived_history_lock_root ON archived_history(lock_root_hash);
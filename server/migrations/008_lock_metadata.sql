@@ -0,0 +1,6 @@
+-- ============================================================================
+-- Free-form wallet metadata (name, description, tags, ...)
+-- ============================================================================
+
+-- JSON document supplied by participants; NULL when none has been set
+ALTER TABLE locks ADD COLUMN metadata_json TEXT;
@@ -0,0 +1,16 @@
+-- ============================================================================
+-- Audit trail of operator actions on proposals
+-- ============================================================================
+
+CREATE TABLE IF NOT EXISTS proposal_events (
+    id TEXT PRIMARY KEY,                    -- UUID
+    proposal_id TEXT NOT NULL,
+    event_type TEXT NOT NULL,               -- e.g. forced_ready
+    actor TEXT NOT NULL,                    -- Who performed the action
+    reason TEXT,                            -- Free-text justification
+    created_at TEXT NOT NULL,
+    
+    FOREIGN KEY (proposal_id) REFERENCES proposals(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_proposal_events_proposal ON proposal_events(proposal_id, created_at);
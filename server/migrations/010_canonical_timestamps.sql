@@ -0,0 +1,65 @@
+-- ============================================================================
+-- Canonical timestamps: UTC, `Z` suffix, exactly six fractional digits
+-- ============================================================================
+
+-- Timestamps are compared as TEXT, which only orders correctly when every value has the
+-- same shape. Earlier rows were written with a `+00:00` suffix and 0, 3, 6 or 9 fractional
+-- digits; rewrite them without losing precision (truncating to microseconds).
+UPDATE locks SET created_at = substr(CASE WHEN instr(created_at, '.') > 0 THEN substr(created_at, 1, length(created_at) - 6) ELSE substr(created_at, 1, 19) || '.' END || '000000', 1, 26) || 'Z'
+WHERE created_at LIKE '%+00:00';
+UPDATE proposals SET created_at = substr(CASE WHEN instr(created_at, '.') > 0 THEN substr(created_at, 1, length(created_at) - 6) ELSE substr(created_at, 1, 19) || '.' END || '000000', 1, 26) || 'Z'
+WHERE created_at LIKE '%+00:00';
+UPDATE proposals SET updated_at = substr(CASE WHEN instr(updated_at, '.') > 0 THEN substr(updated_at, 1, length(updated_at) - 6) ELSE substr(updated_at, 1, 19) || '.' END || '000000', 1, 26) || 'Z'
+WHERE updated_at LIKE '%+00:00';
+UPDATE proposal_signatures SET signed_at = substr(CASE WHEN instr(signed_at, '.') > 0 THEN substr(signed_at, 1, length(signed_at) - 6) ELSE substr(signed_at, 1, 19) || '.' END || '000000', 1, 26) || 'Z'
+WHERE signed_at LIKE '%+00:00';
+UPDATE sign_nonces SET updated_at = substr(CASE WHEN instr(updated_at, '.') > 0 THEN substr(updated_at, 1, length(updated_at) - 6) ELSE substr(updated_at, 1, 19) || '.' END || '000000', 1, 26) || 'Z'
+WHERE updated_at LIKE '%+00:00';
+UPDATE transaction_history SET created_at = substr(CASE WHEN instr(created_at, '.') > 0 THEN substr(created_at, 1, length(created_at) - 6) ELSE substr(created_at, 1, 19) || '.' END || '000000', 1, 26) || 'Z'
+WHERE created_at LIKE '%+00:00';
+UPDATE transaction_history SET broadcast_at = substr(CASE WHEN instr(broadcast_at, '.') > 0 THEN substr(broadcast_at, 1, length(broadcast_at) - 6) ELSE substr(broadcast_at, 1, 19) || '.' END || '000000', 1, 26) || 'Z'
+WHERE broadcast_at LIKE '%+00:00';
+UPDATE transaction_history SET confirmed_at = substr(CASE WHEN instr(confirmed_at, '.') > 0 THEN substr(confirmed_at, 1, length(confirmed_at) - 6) ELSE substr(confirmed_at, 1, 19) || '.' END || '000000', 1, 26) || 'Z'
+WHERE confirmed_at LIKE '%+00:00';
+UPDATE proposal_comments SET created_at = substr(CASE WHEN instr(created_at, '.') > 0 THEN substr(created_at, 1, length(created_at) - 6) ELSE substr(created_at, 1, 19) || '.' END || '000000', 1, 26) || 'Z'
+WHERE created_at LIKE '%+00:00';
+UPDATE archived_history SET created_at = substr(CASE WHEN instr(created_at, '.') > 0 THEN substr(created_at, 1, length(created_at) - 6) ELSE substr(created_at, 1, 19) || '.' END || '000000', 1, 26) || 'Z'
+WHERE created_at LIKE '%+00:00';
+UPDATE archived_history SET broadcast_at = substr(CASE WHEN instr(broadcast_at, '.') > 0 THEN substr(broadcast_at, 1, length(broadcast_at) - 6) ELSE substr(broadcast_at, 1, 19) || '.' END || '000000', 1, 26) || 'Z'
+WHERE broadcast_at LIKE '%+00:00';
+UPDATE archived_history SET confirmed_at = substr(CASE WHEN instr(confirmed_at, '.') > 0 THEN substr(confirmed_at, 1, length(confirmed_at) - 6) ELSE substr(confirmed_at, 1, 19) || '.' END || '000000', 1, 26) || 'Z'
+WHERE confirmed_at LIKE '%+00:00';
+UPDATE archived_history SET archived_at = substr(CASE WHEN instr(archived_at, '.') > 0 THEN substr(archived_at, 1, length(archived_at) - 6) ELSE substr(archived_at, 1, 19) || '.' END || '000000', 1, 26) || 'Z'
+WHERE archived_at LIKE '%+00:00';
+UPDATE proposal_events SET created_at = substr(CASE WHEN instr(created_at, '.') > 0 THEN substr(created_at, 1, length(created_at) - 6) ELSE substr(created_at, 1, 19) || '.' END || '000000', 1, 26) || 'Z'
+WHERE created_at LIKE '%+00:00';
+
+-- Anything else (client-supplied offsets) is converted by SQLite, at millisecond precision
+UPDATE locks SET created_at = strftime('%Y-%m-%dT%H:%M:%f', created_at) || '000Z'
+WHERE created_at NOT GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9]T[0-9][0-9]:[0-9][0-9]:[0-9][0-9].[0-9][0-9][0-9][0-9][0-9][0-9]Z' AND strftime('%Y-%m-%dT%H:%M:%f', created_at) IS NOT NULL;
+UPDATE proposals SET created_at = strftime('%Y-%m-%dT%H:%M:%f', created_at) || '000Z'
+WHERE created_at NOT GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9]T[0-9][0-9]:[0-9][0-9]:[0-9][0-9].[0-9][0-9][0-9][0-9][0-9][0-9]Z' AND strftime('%Y-%m-%dT%H:%M:%f', created_at) IS NOT NULL;
+UPDATE proposals SET updated_at = strftime('%Y-%m-%dT%H:%M:%f', updated_at) || '000Z'
+WHERE updated_at NOT GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9]T[0-9][0-9]:[0-9][0-9]:[0-9][0-9].[0-9][0-9][0-9][0-9][0-9][0-9]Z' AND strftime('%Y-%m-%dT%H:%M:%f', updated_at) IS NOT NULL;
+UPDATE proposal_signatures SET signed_at = strftime('%Y-%m-%dT%H:%M:%f', signed_at) || '000Z'
+WHERE signed_at NOT GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9]T[0-9][0-9]:[0-9][0-9]:[0-9][0-9].[0-9][0-9][0-9][0-9][0-9][0-9]Z' AND strftime('%Y-%m-%dT%H:%M:%f', signed_at) IS NOT NULL;
+UPDATE sign_nonces SET updated_at = strftime('%Y-%m-%dT%H:%M:%f', updated_at) || '000Z'
+WHERE updated_at NOT GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9]T[0-9][0-9]:[0-9][0-9]:[0-9][0-9].[0-9][0-9][0-9][0-9][0-9][0-9]Z' AND strftime('%Y-%m-%dT%H:%M:%f', updated_at) IS NOT NULL;
+UPDATE transaction_history SET created_at = strftime('%Y-%m-%dT%H:%M:%f', created_at) || '000Z'
+WHERE created_at NOT GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9]T[0-9][0-9]:[0-9][0-9]:[0-9][0-9].[0-9][0-9][0-9][0-9][0-9][0-9]Z' AND strftime('%Y-%m-%dT%H:%M:%f', created_at) IS NOT NULL;
+UPDATE transaction_history SET broadcast_at = strftime('%Y-%m-%dT%H:%M:%f', broadcast_at) || '000Z'
+WHERE broadcast_at NOT GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9]T[0-9][0-9]:[0-9][0-9]:[0-9][0-9].[0-9][0-9][0-9][0-9][0-9][0-9]Z' AND strftime('%Y-%m-%dT%H:%M:%f', broadcast_at) IS NOT NULL;
+UPDATE transaction_history SET confirmed_at = strftime('%Y-%m-%dT%H:%M:%f', confirmed_at) || '000Z'
+WHERE confirmed_at NOT GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9]T[0-9][0-9]:[0-9][0-9]:[0-9][0-9].[0-9][0-9][0-9][0-9][0-9][0-9]Z' AND strftime('%Y-%m-%dT%H:%M:%f', confirmed_at) IS NOT NULL;
+UPDATE proposal_comments SET created_at = strftime('%Y-%m-%dT%H:%M:%f', created_at) || '000Z'
+WHERE created_at NOT GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9]T[0-9][0-9]:[0-9][0-9]:[0-9][0-9].[0-9][0-9][0-9][0-9][0-9][0-9]Z' AND strftime('%Y-%m-%dT%H:%M:%f', created_at) IS NOT NULL;
+UPDATE archived_history SET created_at = strftime('%Y-%m-%dT%H:%M:%f', created_at) || '000Z'
+WHERE created_at NOT GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9]T[0-9][0-9]:[0-9][0-9]:[0-9][0-9].[0-9][0-9][0-9][0-9][0-9][0-9]Z' AND strftime('%Y-%m-%dT%H:%M:%f', created_at) IS NOT NULL;
+UPDATE archived_history SET broadcast_at = strftime('%Y-%m-%dT%H:%M:%f', broadcast_at) || '000Z'
+WHERE broadcast_at NOT GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9]T[0-9][0-9]:[0-9][0-9]:[0-9][0-9].[0-9][0-9][0-9][0-9][0-9][0-9]Z' AND strftime('%Y-%m-%dT%H:%M:%f', broadcast_at) IS NOT NULL;
+UPDATE archived_history SET confirmed_at = strftime('%Y-%m-%dT%H:%M:%f', confirmed_at) || '000Z'
+WHERE confirmed_at NOT GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9]T[0-9][0-9]:[0-9][0-9]:[0-9][0-9].[0-9][0-9][0-9][0-9][0-9][0-9]Z' AND strftime('%Y-%m-%dT%H:%M:%f', confirmed_at) IS NOT NULL;
+UPDATE archived_history SET archived_at = strftime('%Y-%m-%dT%H:%M:%f', archived_at) || '000Z'
+WHERE archived_at NOT GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9]T[0-9][0-9]:[0-9][0-9]:[0-9][0-9].[0-9][0-9][0-9][0-9][0-9][0-9]Z' AND strftime('%Y-%m-%dT%H:%M:%f', archived_at) IS NOT NULL;
+UPDATE proposal_events SET created_at = strftime('%Y-%m-%dT%H:%M:%f', created_at) || '000Z'
+WHERE created_at NOT GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9]T[0-9][0-9]:[0-9][0-9]:[0-9][0-9].[0-9][0-9][0-9][0-9][0-9][0-9]Z' AND strftime('%Y-%m-%dT%H:%M:%f', created_at) IS NOT NULL;
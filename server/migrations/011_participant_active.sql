@@ -0,0 +1,7 @@
+-- ============================================================================
+-- Advisory availability flag for wallet participants
+-- ============================================================================
+
+-- 0 while a co-signer is unavailable (e.g. on leave); only affects reachability hints,
+-- never the cryptographic threshold
+ALTER TABLE lock_participants ADD COLUMN active INTEGER NOT NULL DEFAULT 1;
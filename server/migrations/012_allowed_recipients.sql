@@ -0,0 +1,13 @@
+-- ============================================================================
+-- Per-wallet recipient allow-list (spending policy)
+-- ============================================================================
+
+-- A wallet with no rows here may pay any recipient
+CREATE TABLE IF NOT EXISTS lock_allowed_recipients (
+    lock_root_hash TEXT NOT NULL,
+    recipient TEXT NOT NULL,
+    added_at TEXT NOT NULL,
+    
+    PRIMARY KEY (lock_root_hash, recipient),
+    FOREIGN KEY (lock_root_hash) REFERENCES locks(lock_root_hash) ON DELETE CASCADE
+);
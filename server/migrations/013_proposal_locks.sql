@@ -0,0 +1,16 @@
+-- ============================================================================
+-- Proposals spending from more than one wallet
+-- ============================================================================
+
+-- Wallets a proposal spends from besides proposals.lock_root_hash; each must reach its
+-- own threshold before the proposal is ready. Single-wallet proposals have no rows here.
+CREATE TABLE IF NOT EXISTS proposal_locks (
+    proposal_id TEXT NOT NULL,
+    lock_root_hash TEXT NOT NULL,
+    
+    PRIMARY KEY (proposal_id, lock_root_hash),
+    FOREIGN KEY (proposal_id) REFERENCES proposals(id) ON DELETE CASCADE,
+    FOREIGN KEY (lock_root_hash) REFERENCES locks(lock_root_hash) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_proposal_locks_lock_root_hash ON proposal_locks(lock_root_hash);
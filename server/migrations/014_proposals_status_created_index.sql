@@ -0,0 +1,6 @@
+-- ============================================================================
+-- Ready-to-broadcast queue
+-- ============================================================================
+
+-- Serves GET /api/proposals/ready: filter on status, oldest first
+CREATE INDEX IF NOT EXISTS idx_proposals_status_created_at ON proposals(status, created_at);
@@ -0,0 +1,9 @@
+-- ============================================================================
+-- Per-proposal expiry
+-- ============================================================================
+
+-- Canonical UTC RFC 3339; NULL means the proposal never expires. Signing is refused once
+-- it has passed.
+ALTER TABLE proposals ADD COLUMN expires_at TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_proposals_status_expires_at ON proposals(status, expires_at);
@@ -0,0 +1,22 @@
+-- ============================================================================
+-- Signing delegation between wallet participants
+-- ============================================================================
+
+-- While active (not revoked, not expired) to_pkh may sign on from_pkh's behalf in this
+-- wallet; such signatures count toward from_pkh
+CREATE TABLE IF NOT EXISTS delegations (
+    id TEXT PRIMARY KEY,                    -- UUID
+    lock_root_hash TEXT NOT NULL,
+    from_pkh TEXT NOT NULL,
+    to_pkh TEXT NOT NULL,
+    expires_at TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    revoked_at TEXT,
+    
+    FOREIGN KEY (lock_root_hash) REFERENCES locks(lock_root_hash) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_delegations_lookup ON delegations(lock_root_hash, from_pkh, to_pkh);
+
+-- Participant who actually submitted a delegated signature; NULL when signer_pkh signed
+ALTER TABLE proposal_signatures ADD COLUMN delegate_pkh TEXT;
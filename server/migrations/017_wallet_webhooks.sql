@@ -0,0 +1,8 @@
+-- ============================================================================
+-- Per-wallet event webhooks
+-- ============================================================================
+
+-- When set, this wallet's proposal events go here instead of the global sink
+ALTER TABLE locks ADD COLUMN webhook_url TEXT;
+-- Optional HMAC-SHA256 key for the X-Agora-Signature header; never returned by the API
+ALTER TABLE locks ADD COLUMN webhook_secret TEXT;
@@ -0,0 +1,11 @@
+-- ============================================================================
+-- Enforce one row per (wallet, participant)
+-- ============================================================================
+
+-- 001 declares this as the primary key, but a lock_participants table that predates it
+-- is left untouched by CREATE TABLE IF NOT EXISTS. If duplicates exist this migration
+-- fails with "UNIQUE constraint failed"; list them with
+--   SELECT lock_root_hash, pkh, COUNT(*) FROM lock_participants
+--   GROUP BY lock_root_hash, pkh HAVING COUNT(*) > 1;
+-- and delete the extra rows before restarting
+CREATE UNIQUE INDEX IF NOT EXISTS idx_lock_participants_unique ON lock_participants(lock_root_hash, pkh);
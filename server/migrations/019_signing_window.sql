@@ -0,0 +1,7 @@
+-- ============================================================================
+-- Per-wallet signing window
+-- ============================================================================
+
+-- Seconds after creation within which a proposal must collect its signatures; NULL means
+-- no limit. Applied when a proposal is created by capping its expires_at.
+ALTER TABLE locks ADD COLUMN signing_window_secs INTEGER;
@@ -0,0 +1,9 @@
+-- ============================================================================
+-- Proposal types
+-- ============================================================================
+
+-- What the proposal is for (payment, transfer, other); validated by the server so new
+-- types need no schema change. Existing proposals are 'other'.
+ALTER TABLE proposals ADD COLUMN proposal_type TEXT NOT NULL DEFAULT 'other';
+
+CREATE INDEX IF NOT EXISTS idx_proposals_proposal_type ON proposals(proposal_type);
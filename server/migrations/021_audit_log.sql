@@ -0,0 +1,30 @@
+-- ============================================================================
+-- Append-only security audit log of mutating requests
+-- ============================================================================
+
+CREATE TABLE IF NOT EXISTS audit_log (
+    id TEXT PRIMARY KEY,                    -- UUID
+    created_at TEXT NOT NULL,
+    method TEXT NOT NULL,
+    path TEXT NOT NULL,                     -- Request path without the query string
+    status INTEGER NOT NULL,                -- Response status code
+    actor_pkh TEXT,                         -- PKH named in the request body, when there is one
+    admin INTEGER NOT NULL DEFAULT 0,       -- A valid admin bearer token was presented
+    source_ip TEXT,
+    resource_id TEXT                        -- Proposal id or lock_root_hash the request touched
+);
+
+CREATE INDEX IF NOT EXISTS idx_audit_log_created ON audit_log(created_at);
+CREATE INDEX IF NOT EXISTS idx_audit_log_actor ON audit_log(actor_pkh, created_at);
+CREATE INDEX IF NOT EXISTS idx_audit_log_source_ip ON audit_log(source_ip, created_at);
+CREATE INDEX IF NOT EXISTS idx_audit_log_resource ON audit_log(resource_id, created_at);
+
+CREATE TRIGGER IF NOT EXISTS audit_log_no_update BEFORE UPDATE ON audit_log
+BEGIN
+    SELECT RAISE(ABORT, 'audit_log is append-only');
+END;
+
+CREATE TRIGGER IF NOT EXISTS audit_log_no_delete BEFORE DELETE ON audit_log
+BEGIN
+    SELECT RAISE(ABORT, 'audit_log is append-only');
+END;
@@ -0,0 +1,7 @@
+-- ============================================================================
+-- Required signers: participants whose signature every proposal needs
+-- ============================================================================
+
+-- On top of the numeric threshold, a proposal is only ready once every required
+-- participant of each wallet it spends from has signed
+ALTER TABLE lock_participants ADD COLUMN required INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,5 @@
+-- ============================================================================
+-- Free-text provenance for a signature (e.g. which device or person it came from)
+-- ============================================================================
+
+ALTER TABLE proposal_signatures ADD COLUMN signer_note TEXT;
@@ -0,0 +1,9 @@
+-- ============================================================================
+-- When each proposer last discarded drafts, so listings that showed those drafts can tell
+-- they changed (deleted rows leave no updated_at behind)
+-- ============================================================================
+
+CREATE TABLE IF NOT EXISTS draft_discards (
+    proposer_pkh TEXT PRIMARY KEY,
+    discarded_at TEXT NOT NULL
+);
@@ -0,0 +1,252 @@
+use axum::{
+    async_trait,
+    extract::{FromRequestParts, Query, State},
+    http::{header, request::Parts, HeaderMap},
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::{IntoParams, Modify, OpenApi, ToSchema};
+use crate::api::AppState;
+use crate::api::pagination::{Counted, Pagination, TOTAL_COUNT_HEADER};
+use crate::config::Config;
+use crate::db::{format_rfc3339, parse_relative_duration, AuditLogEntry, DbPool, Pkh};
+use crate::error::{AppError, ErrorBody};
+
+/// Guard for break-glass endpoints: requires `Authorization: Bearer <ADMIN_TOKEN>`.
+/// When no admin token is configured every admin request is rejected.
+pub struct AdminAuth;
+
+#[async_trait]
+impl FromRequestParts<AppState> for AdminAuth {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
+        check_admin_token(&parts.headers, &state.config)?;
+        Ok(AdminAuth)
+    }
+}
+
+/// The check behind `AdminAuth`, for callers that only have the headers
+pub(crate) fn check_admin_token(headers: &HeaderMap, config: &Config) -> Result<(), AppError> {
+    let expected = config.admin_token.as_deref().ok_or_else(|| {
+        AppError::Unauthorized("Admin endpoints are disabled (ADMIN_TOKEN is not set)".to_string())
+    })?;
+    let provided = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .ok_or_else(|| AppError::Unauthorized("Missing admin bearer token".to_string()))?;
+    
+    if !constant_time_eq(provided.as_bytes(), expected.as_bytes()) {
+        return Err(AppError::Unauthorized("Invalid admin token".to_string()));
+    }
+    Ok(())
+}
+
+/// Compares without short-circuiting so response timing doesn't leak the matching prefix
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Registers the `admin_token` bearer scheme referenced by admin endpoints
+pub struct AdminSecurity;
+
+impl Modify for AdminSecurity {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "admin_token",
+            SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).build()),
+        );
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct IntegrityCheck {
+    pub name: String,
+    pub count: i64,
+    pub sample_ids: Vec<String>, // At most INTEGRITY_SAMPLE_SIZE
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct IntegrityReport {
+    pub consistent: bool, // True when every check found nothing
+    pub checks: Vec<IntegrityCheck>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct AuditLogQuery {
+    pub actor_pkh: Option<Pkh>,
+    pub source_ip: Option<String>,
+    pub resource_id: Option<String>, // Proposal id or lock_root_hash
+    pub since: Option<String>,       // Only entries within this window, e.g. 24h, 7d
+    pub limit: Option<i64>,          // Defaults to DEFAULT_PAGE_SIZE, clamped to MAX_PAGE_SIZE
+    pub offset: Option<i64>,
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(get_integrity, list_audit_log))]
+pub struct AdminApi;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/integrity", get(get_integrity))
+        .route("/audit", get(list_audit_log))
+}
+
+const INTEGRITY_SAMPLE_SIZE: i64 = 10;
+
+/// Each query selects one identifying string per inconsistent row
+const INTEGRITY_CHECKS: &[(&str, &str)] = &[
+    (
+        "locks_without_participants",
+        "SELECT l.lock_root_hash FROM locks l
+         WHERE NOT EXISTS (SELECT 1 FROM lock_participants lp WHERE lp.lock_root_hash = l.lock_root_hash)",
+    ),
+    (
+        "locks_with_participant_count_mismatch",
+        "SELECT l.lock_root_hash FROM locks l
+         WHERE l.total_signers != (SELECT COUNT(*) FROM lock_participants lp WHERE lp.lock_root_hash = l.lock_root_hash)",
+    ),
+    (
+        "participants_without_lock",
+        "SELECT lp.lock_root_hash || ':' || lp.pkh FROM lock_participants lp
+         WHERE NOT EXISTS (SELECT 1 FROM locks l WHERE l.lock_root_hash = lp.lock_root_hash)",
+    ),
+    (
+        "proposals_without_lock",
+        "SELECT p.id FROM proposals p
+         WHERE NOT EXISTS (SELECT 1 FROM locks l WHERE l.lock_root_hash = p.lock_root_hash)",
+    ),
+    (
+        "signatures_without_proposal",
+        "SELECT s.proposal_id || ':' || s.signer_pkh FROM proposal_signatures s
+         WHERE NOT EXISTS (SELECT 1 FROM proposals p WHERE p.id = s.proposal_id)",
+    ),
+    (
+        "comments_without_proposal",
+        "SELECT c.id FROM proposal_comments c
+         WHERE NOT EXISTS (SELECT 1 FROM proposals p WHERE p.id = c.proposal_id)",
+    ),
+    (
+        "proposal_locks_dangling",
+        "SELECT pl.proposal_id || ':' || pl.lock_root_hash FROM proposal_locks pl
+         WHERE NOT EXISTS (SELECT 1 FROM proposals p WHERE p.id = pl.proposal_id)
+            OR NOT EXISTS (SELECT 1 FROM locks l WHERE l.lock_root_hash = pl.lock_root_hash)",
+    ),
+    (
+        "history_without_lock",
+        "SELECT h.id FROM transaction_history h
+         WHERE NOT EXISTS (SELECT 1 FROM locks l WHERE l.lock_root_hash = h.lock_root_hash)",
+    ),
+];
+
+/// Read-only consistency scan for rows left behind by partial writes. Reports what it
+/// finds; fixing it is left to the operator
+#[utoipa::path(
+    get, path = "/integrity", tag = "admin",
+    security(("admin_token" = [])),
+    responses((status = 200, body = IntegrityReport), (status = 401, body = ErrorBody))
+)]
+async fn get_integrity(
+    _admin: AdminAuth,
+    State(pool): State<DbPool>,
+) -> Result<Json<IntegrityReport>, AppError> {
+    let mut checks = Vec::with_capacity(INTEGRITY_CHECKS.len());
+    for (name, sql) in INTEGRITY_CHECKS {
+        let count: i64 = sqlx::query_scalar(&format!("SELECT COUNT(*) FROM ({})", sql))
+            .fetch_one(&pool.read)
+            .await?;
+        let sample_ids: Vec<String> = sqlx::query_scalar(&format!("{} ORDER BY 1 LIMIT ?", sql))
+            .bind(INTEGRITY_SAMPLE_SIZE)
+            .fetch_all(&pool.read)
+            .await?;
+        checks.push(IntegrityCheck { name: name.to_string(), count, sample_ids });
+    }
+    
+    let consistent = checks.iter().all(|c| c.count == 0);
+    if !consistent {
+        let failing: Vec<&str> = checks.iter().filter(|c| c.count > 0).map(|c| c.name.as_str()).collect();
+        tracing::warn!(?failing, "Integrity check found inconsistent rows");
+    }
+    Ok(Json(IntegrityReport { consistent, checks }))
+}
+
+/// Security audit log of mutating requests, newest first
+#[utoipa::path(
+    get, path = "/audit", tag = "admin",
+    params(AuditLogQuery),
+    security(("admin_token" = [])),
+    responses(
+        (status = 200, body = Vec<AuditLogEntry>, headers(("X-Total-Count" = i64))),
+        (status = 400, body = ErrorBody),
+        (status = 401, body = ErrorBody),
+    )
+)]
+async fn list_audit_log(
+    _admin: AdminAuth,
+    State(pool): State<DbPool>,
+    State(config): State<Arc<Config>>,
+    Query(params): Query<AuditLogQuery>,
+) -> Result<Response, AppError> {
+    let page = Pagination::from_query(params.limit, params.offset, &config)?;
+    
+    let mut conditions = Vec::new();
+    let mut binds: Vec<String> = Vec::new();
+    if let Some(actor_pkh) = &params.actor_pkh {
+        conditions.push("actor_pkh = ?");
+        binds.push(actor_pkh.to_string());
+    }
+    if let Some(source_ip) = &params.source_ip {
+        conditions.push("source_ip = ?");
+        binds.push(source_ip.clone());
+    }
+    if let Some(resource_id) = &params.resource_id {
+        conditions.push("resource_id = ?");
+        binds.push(resource_id.clone());
+    }
+    if let Some(since) = &params.since {
+        let window = parse_relative_duration(since).ok_or_else(|| AppError::InvalidInput(
+            format!("Invalid since: {} (expected a duration like 24h, 7d or 30d)", since)
+        ))?;
+        let cutoff = chrono::Utc::now().checked_sub_signed(window)
+            .ok_or_else(|| AppError::InvalidInput(format!("since is out of range: {}", since)))?;
+        conditions.push("created_at >= ?");
+        binds.push(format_rfc3339(cutoff));
+    }
+    let where_clause = if conditions.is_empty() {
+        String::new()
+    } else {
+        format!(" WHERE {}", conditions.join(" AND "))
+    };
+    
+    let page_query = format!(
+        "SELECT *, COUNT(*) OVER () AS total_count FROM audit_log{} ORDER BY created_at DESC, id DESC LIMIT ? OFFSET ?",
+        where_clause
+    );
+    let mut entries_query = sqlx::query_as::<_, Counted<AuditLogEntry>>(&page_query);
+    for value in &binds {
+        entries_query = entries_query.bind(value);
+    }
+    let rows = entries_query.bind(page.limit).bind(page.offset).fetch_all(&pool.read).await?;
+    let (entries, total) = Counted::split(rows);
+    
+    // Past the end of the set the page is empty, so the total needs its own count
+    let total = match total {
+        Some(total) => total,
+        None if page.offset == 0 => 0,
+        None => {
+            let count_query = format!("SELECT COUNT(*) FROM audit_log{}", where_clause);
+            let mut total_query = sqlx::query_scalar::<_, i64>(&count_query);
+            for value in &binds {
+                total_query = total_query.bind(value);
+            }
+            total_query.fetch_one(&pool.read).await?
+        }
+    };
+    Ok(([(TOTAL_COUNT_HEADER, total.to_string())], Json(entries)).into_response())
+}
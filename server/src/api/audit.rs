@@ -0,0 +1,150 @@
+use std::net::{IpAddr, SocketAddr};
+
+use axum::{
+    body::{self, Body},
+    extract::{ConnectInfo, OriginalUri, RawPathParams, Request, State},
+    http::{HeaderMap, Method},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde_json::Value;
+use uuid::Uuid;
+use crate::api::AppState;
+use crate::api::admin::check_admin_token;
+use crate::db::{now_rfc3339, Pkh};
+use crate::error::AppError;
+
+/// Largest request body buffered to find the actor; matches axum's default body limit, so
+/// the handler would have refused anything bigger anyway
+const MAX_AUDITED_BODY_BYTES: usize = 2 * 1024 * 1024;
+
+/// Request body fields naming who is acting, most specific first
+const ACTOR_FIELDS: &[&str] = &[
+    "actor_pkh", "proposer_pkh", "signer_pkh", "author_pkh", "sender_pkh", "from_pkh",
+    "created_by_pkh", "_broadcaster_pkh", "pkh",
+];
+
+/// Path parameters and (for creates) response fields identifying the affected resource
+const RESOURCE_FIELDS: &[&str] = &["id", "lock_root_hash"];
+
+/// Route layer that appends one `audit_log` row per mutating request that reaches a handler,
+/// whatever its outcome. Reads pass straight through, as does everything when AUDIT_LOG=false
+pub async fn record_mutations(
+    State(state): State<AppState>,
+    path_params: Option<RawPathParams>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !state.config.audit_log || matches!(*request.method(), Method::GET | Method::HEAD | Method::OPTIONS) {
+        return next.run(request).await;
+    }
+    let method = request.method().to_string();
+    // Nesting strips the group prefix from the URI; the original keeps the full path
+    let path = match request.extensions().get::<OriginalUri>() {
+        Some(OriginalUri(uri)) => uri.path().to_string(),
+        None => request.uri().path().to_string(),
+    };
+    let admin = check_admin_token(request.headers(), &state.config).is_ok();
+    let source_ip = client_ip(&request, state.config.trust_forwarded_for);
+    let path_resource = path_params.and_then(|params| {
+        RESOURCE_FIELDS.iter().find_map(|field| {
+            params.iter().find(|(key, _)| key == field).map(|(_, value)| value.to_string())
+        })
+    });
+    
+    let (parts, body) = request.into_parts();
+    let (actor_pkh, response) = match body::to_bytes(body, MAX_AUDITED_BODY_BYTES).await {
+        Ok(bytes) => {
+            let actor_pkh = actor_from_body(&bytes);
+            (actor_pkh, next.run(Request::from_parts(parts, Body::from(bytes))).await)
+        }
+        Err(_) => (None, AppError::InvalidInput("Request body is too large".to_string()).into_response()),
+    };
+    
+    // Creates have no id in the path; the new resource's id comes back in the response
+    let (resource_id, response) = match path_resource {
+        Some(id) => (Some(id), response),
+        None if response.status().is_success() => resource_from_response(response).await,
+        None => (None, response),
+    };
+
+    let result = sqlx::query(
+        "INSERT INTO audit_log (id, created_at, method, path, status, actor_pkh, admin, source_ip, resource_id)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)"
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(now_rfc3339())
+    .bind(&method)
+    .bind(&path)
+    .bind(response.status().as_u16() as i64)
+    .bind(&actor_pkh)
+    .bind(admin)
+    .bind(&source_ip)
+    .bind(&resource_id)
+    .execute(&state.pool.write)
+    .await;
+    // The request already ran, so a lost audit row is logged rather than turned into an error
+    if let Err(e) = result {
+        tracing::error!(%method, %path, "Failed to write audit log entry: {}", e);
+    }
+    response
+}
+
+/// First actor field in a JSON object body holding a valid PKH
+fn actor_from_body(bytes: &[u8]) -> Option<Pkh> {
+    let body: Value = serde_json::from_slice(bytes).ok()?;
+    ACTOR_FIELDS.iter().find_map(|field| {
+        body.get(field).and_then(Value::as_str).and_then(|value| Pkh::parse(value).ok())
+    })
+}
+
+/// Pulls `id` or `lock_root_hash` out of a JSON response, handing back an identical response
+async fn resource_from_response(response: Response) -> (Option<String>, Response) {
+    let (parts, body) = response.into_parts();
+    let Ok(bytes) = body::to_bytes(body, usize::MAX).await else {
+        return (None, Response::from_parts(parts, Body::empty()));
+    };
+    let resource_id = serde_json::from_slice::<Value>(&bytes).ok().and_then(|body| {
+        RESOURCE_FIELDS.iter().find_map(|field| body.get(field).and_then(Value::as_str).map(String::from))
+    });
+    (resource_id, Response::from_parts(parts, Body::from(bytes)))
+}
+
+/// Peer address of the connection, or with TRUST_FORWARDED_FOR the left-most valid
+/// X-Forwarded-For entry (the client as seen by the first proxy)
+fn client_ip(request: &Request, trust_forwarded_for: bool) -> Option<String> {
+    if trust_forwarded_for
+        && let Some(ip) = forwarded_for(request.headers())
+    {
+        return Some(ip.to_string());
+    }
+    request.extensions().get::<ConnectInfo<SocketAddr>>().map(|info| info.0.ip().to_string())
+}
+
+fn forwarded_for(headers: &HeaderMap) -> Option<IpAddr> {
+    let value = headers.get("x-forwarded-for")?.to_str().ok()?;
+    value.split(',').next()?.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn actor_prefers_the_most_specific_valid_field() {
+        assert_eq!(actor_from_body(br#"{"pkh":"Pa","actor_pkh":"Pb"}"#).unwrap(), "Pb");
+        assert_eq!(actor_from_body(br#"{"signer_pkh":"Sa","on_behalf_of":"Sb"}"#).unwrap(), "Sa");
+        assert_eq!(actor_from_body(br#"{"actor_pkh":"not base58!","pkh":"Pa"}"#).unwrap(), "Pa");
+        assert!(actor_from_body(br#"{"active":true}"#).is_none());
+        assert!(actor_from_body(b"not json").is_none());
+    }
+
+    #[test]
+    fn forwarded_for_takes_the_first_hop() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-for", "203.0.113.7, 10.0.0.1".parse().unwrap());
+        assert_eq!(forwarded_for(&headers), Some("203.0.113.7".parse().unwrap()));
+        headers.insert("x-forwarded-for", "garbage".parse().unwrap());
+        assert_eq!(forwarded_for(&headers), None);
+    }
+}
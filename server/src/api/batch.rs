@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use axum::{extract::State, routing::post, Json, Router};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use utoipa::{OpenApi, ToSchema};
+use crate::api::AppState;
+use crate::api::json_body::{from_value_strict, JsonBody};
+use crate::api::multisigs::{insert_multisig, CreateMultisigRequest, CreateMultisigResponse};
+use crate::api::proposals::{emit_proposal_created, insert_proposal, CreateProposalRequest, CreateProposalResponse};
+use crate::config::Config;
+use crate::db::DbPool;
+use crate::error::{AppError, ErrorBody};
+use crate::events::EventSink;
+
+const MAX_BATCH_STEPS: usize = 20;
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct BatchStep {
+    pub method: String, // e.g. "POST"
+    pub path: String,   // e.g. "/api/multisigs"
+    #[serde(default)]
+    pub body: serde_json::Value,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BatchStepResponse {
+    pub status: u16,
+    pub body: serde_json::Value, // Same body the standalone endpoint returns
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(run_batch))]
+pub struct BatchApi;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", post(run_batch))
+}
+
+/// Runs mutating calls in order inside one transaction, e.g. create a multisig and its first
+/// proposal in a single round-trip. Supported steps: `POST /api/multisigs` and
+/// `POST /api/proposals`. Any failure rolls back every step and names the one that failed.
+#[utoipa::path(
+    post, path = "", tag = "batch",
+    request_body = Vec<BatchStep>,
+    responses(
+        (status = 200, description = "One response per step, in order", body = Vec<BatchStepResponse>),
+        (status = 400, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    )
+)]
+async fn run_batch(
+    State(pool): State<DbPool>,
+    State(config): State<Arc<Config>>,
+    State(events): State<Arc<dyn EventSink>>,
+    JsonBody(steps): JsonBody<Vec<BatchStep>>,
+) -> Result<Json<Vec<BatchStepResponse>>, AppError> {
+    if steps.is_empty() || steps.len() > MAX_BATCH_STEPS {
+        return Err(AppError::InvalidInput(
+            format!("A batch must have between 1 and {} steps, got {}", MAX_BATCH_STEPS, steps.len())
+        ));
+    }
+    
+    let mut tx = pool.write.begin_with("BEGIN IMMEDIATE").await?;
+    let mut responses = Vec::with_capacity(steps.len());
+    let mut created_proposals = Vec::new();
+    for (i, step) in steps.iter().enumerate() {
+        let at_step = |e| step_error(i, step, e);
+        let body = match (step.method.to_ascii_uppercase().as_str(), step.path.trim_end_matches('/')) {
+            ("POST", "/api/multisigs") => {
+                let req: CreateMultisigRequest = step_body(step, config.strict_json).map_err(at_step)?;
+                insert_multisig(&mut tx, &config, &req).await.map_err(at_step)?;
+                serde_json::to_value(CreateMultisigResponse { lock_root_hash: req.lock_root_hash })
+            }
+            ("POST", "/api/proposals") => {
+                let req: CreateProposalRequest = step_body(step, config.strict_json).map_err(at_step)?;
+                let created = insert_proposal(&mut tx, &config, &req).await.map_err(at_step)?;
+                let response = CreateProposalResponse { id: created.id.clone(), tx_id: created.tx_id.clone() };
+                created_proposals.push((req, created));
+                serde_json::to_value(response)
+            }
+            _ => return Err(at_step(AppError::InvalidInput(
+                "unsupported in a batch (supported: POST /api/multisigs, POST /api/proposals)".to_string()
+            ))),
+        }
+        .map_err(|e| AppError::Internal(format!("Failed to serialize batch response: {}", e)))?;
+        responses.push(BatchStepResponse { status: 200, body });
+    }
+    tx.commit().await?;
+    
+    // Only announce proposals once they are durable
+    for (req, created) in &created_proposals {
+        emit_proposal_created(events.as_ref(), req, created);
+    }
+    tracing::info!(steps = steps.len(), "Batch committed");
+    
+    Ok(Json(responses))
+}
+
+fn step_body<T: DeserializeOwned + ToSchema>(step: &BatchStep, strict: bool) -> Result<T, AppError> {
+    if strict {
+        return from_value_strict(step.body.clone());
+    }
+    serde_json::from_value(step.body.clone())
+        .map_err(|e| AppError::InvalidInput(format!("invalid body: {}", e)))
+}
+
+/// Prefixes the failing step to the error, keeping its status code
+fn step_error(index: usize, step: &BatchStep, error: AppError) -> AppError {
+    let at = |msg: String| format!("Batch step {} ({} {}) failed, nothing was applied: {}", index, step.method, step.path, msg);
+    match error {
+        AppError::InvalidInput(msg) => AppError::InvalidInput(at(msg)),
+        AppError::NotFound(msg) => AppError::NotFound(at(msg)),
+        AppError::Unauthorized(msg) => AppError::Unauthorized(at(msg)),
+        AppError::ServiceUnavailable(msg) => AppError::ServiceUnavailable(at(msg)),
+        other => other,
+    }
+}
@@ -0,0 +1,39 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::{
+    extract::{Request, State},
+    http::Method,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use tokio::sync::Semaphore;
+use crate::error::AppError;
+
+/// Caps in-flight mutating requests for one route group so load spikes queue in front of
+/// SQLite's single writer instead of piling up on the pool
+#[derive(Clone)]
+pub struct WriteLimit {
+    permits: Arc<Semaphore>,
+    max_wait: Duration,
+}
+
+impl WriteLimit {
+    pub fn new(max_in_flight: usize, max_wait: Duration) -> Self {
+        Self { permits: Arc::new(Semaphore::new(max_in_flight)), max_wait }
+    }
+}
+
+/// Holds a permit for the duration of each mutating request; reads pass straight through.
+/// A request that can't get a permit within the wait is rejected with 503
+pub async fn limit_writes(State(limit): State<WriteLimit>, request: Request, next: Next) -> Response {
+    if matches!(*request.method(), Method::GET | Method::HEAD | Method::OPTIONS) {
+        return next.run(request).await;
+    }
+    match tokio::time::timeout(limit.max_wait, limit.permits.acquire()).await {
+        Ok(Ok(_permit)) => next.run(request).await,
+        _ => AppError::ServiceUnavailable(
+            "Too many concurrent write requests; try again shortly".to_string()
+        ).into_response(),
+    }
+}
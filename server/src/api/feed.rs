@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Query, State},
+    routing::get,
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, OpenApi, ToSchema};
+use crate::api::AppState;
+use crate::api::pagination::Pagination;
+use crate::api::proposals::{decode_stored_json, seed_responses, SeedResponse, SeedSummary};
+use crate::config::Config;
+use crate::db::{DbPool, FeedItemRow, LockRootHash, Pkh};
+use crate::error::{AppError, ErrorBody};
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct FeedQuery {
+    pub pkh: Pkh,
+    pub cursor: Option<String>, // next_cursor from the previous page
+    pub limit: Option<i64>, // Defaults to DEFAULT_PAGE_SIZE, clamped to MAX_PAGE_SIZE
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FeedItem {
+    #[serde(rename = "type")]
+    pub item_type: String, // "proposal" (actionable) or "history" (completed)
+    pub id: String,
+    pub tx_id: String,
+    pub lock_root_hash: LockRootHash,
+    pub proposer_pkh: Pkh,
+    pub status: String,
+    pub total_input_nicks: i64,
+    pub total_input_display: String,
+    pub seeds: Vec<SeedResponse>,
+    pub seeds_corrupt: bool, // Stored seeds failed to parse; `seeds` is empty, not "no recipients"
+    pub timestamp: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct FeedResponse {
+    pub items: Vec<FeedItem>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(get_feed))]
+pub struct FeedApi;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(get_feed))
+}
+
+/// Chronological feed (newest first) across all of the PKH's wallets: pending/ready
+/// proposals they can act on, mixed with completed transaction history
+#[utoipa::path(
+    get, path = "", tag = "feed",
+    params(FeedQuery),
+    responses((status = 200, body = FeedResponse), (status = 400, body = ErrorBody))
+)]
+async fn get_feed(
+    State(pool): State<DbPool>,
+    State(config): State<Arc<Config>>,
+    Query(params): Query<FeedQuery>,
+) -> Result<Json<FeedResponse>, AppError> {
+    let limit = Pagination::from_query(params.limit, None, &config)?.limit;
+    
+    // Cursor is "<timestamp>|<id>" of the last item on the previous page
+    let cursor = params.cursor.as_deref()
+        .map(|c| c.split_once('|').ok_or_else(|| AppError::InvalidInput("Invalid cursor".to_string())))
+        .transpose()?;
+    
+    let rows: Vec<FeedItemRow> = sqlx::query_as::<_, FeedItemRow>(
+        "SELECT * FROM (
+             SELECT 'proposal' AS item_type, p.id, p.tx_id, p.lock_root_hash, p.proposer_pkh, p.status,
+                    p.total_input_nicks, p.seeds_json, p.updated_at AS timestamp
+             FROM proposals p
+             WHERE p.status IN ('pending', 'ready')
+               AND (p.lock_root_hash IN (SELECT lock_root_hash FROM lock_participants WHERE pkh = ?1)
+                    OR p.id IN (SELECT pl.proposal_id FROM proposal_locks pl
+                                INNER JOIN lock_participants lp ON lp.lock_root_hash = pl.lock_root_hash
+                                WHERE lp.pkh = ?1))
+             UNION ALL
+             SELECT 'history' AS item_type, h.id, h.tx_id, h.lock_root_hash, h.proposer_pkh, h.status,
+                    h.total_input_nicks, h.seeds_json,
+                    COALESCE(h.confirmed_at, h.broadcast_at, h.created_at) AS timestamp
+             FROM transaction_history h
+             WHERE h.lock_root_hash IN (SELECT lock_root_hash FROM lock_participants WHERE pkh = ?1)
+         )
+         WHERE ?2 IS NULL OR timestamp < ?2 OR (timestamp = ?2 AND id < ?3)
+         ORDER BY timestamp DESC, id DESC
+         LIMIT ?4"
+    )
+    .bind(&params.pkh)
+    .bind(cursor.map(|(ts, _)| ts))
+    .bind(cursor.map(|(_, id)| id))
+    .bind(limit + 1)
+    .fetch_all(&pool.read)
+    .await?;
+    
+    // One extra row was fetched to tell whether another page exists
+    let has_more = rows.len() as i64 > limit;
+    let items: Vec<FeedItem> = rows.into_iter().take(limit as usize).map(|row| {
+        let table = if row.item_type == "proposal" { "proposals" } else { "transaction_history" };
+        let (seeds, seeds_corrupt) = decode_stored_json::<Vec<SeedSummary>>(table, &row.id, "seeds_json", &row.seeds_json);
+        FeedItem {
+            seeds: seed_responses(seeds, &config),
+            seeds_corrupt,
+            item_type: row.item_type,
+            id: row.id,
+            tx_id: row.tx_id,
+            lock_root_hash: row.lock_root_hash,
+            proposer_pkh: row.proposer_pkh,
+            status: row.status,
+            total_input_nicks: row.total_input_nicks,
+            total_input_display: config.format_nicks(row.total_input_nicks),
+            timestamp: row.timestamp,
+        }
+    }).collect();
+    
+    let next_cursor = if has_more {
+        items.last().map(|item| format!("{}|{}", item.timestamp, item.id))
+    } else {
+        None
+    };
+    
+    Ok(Json(FeedResponse { items, next_cursor }))
+}
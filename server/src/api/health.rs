@@ -0,0 +1,50 @@
+use axum::{extract::State, routing::get, Json, Router};
+use serde::Serialize;
+use sqlx::{Pool, Sqlite};
+use utoipa::{OpenApi, ToSchema};
+use crate::api::AppState;
+use crate::db::DbPool;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PoolStats {
+    pub size: u32,            // Open connections, idle or in use
+    pub idle: usize,          // Open connections not in use; stuck at 0 means the pool is saturated
+    pub max_connections: u32, // Configured ceiling for `size`
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct HealthResponse {
+    pub status: String,
+    pub write_pool: PoolStats,
+    pub read_pool: PoolStats, // Same pool as write_pool unless READ_DATABASE_URL is set
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(get_health))]
+pub struct HealthApi;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(get_health))
+}
+
+/// Liveness plus connection pool utilization, for capacity alerts
+#[utoipa::path(
+    get, path = "", tag = "health",
+    responses((status = 200, body = HealthResponse))
+)]
+async fn get_health(State(pool): State<DbPool>) -> Json<HealthResponse> {
+    Json(HealthResponse {
+        status: "ok".to_string(),
+        write_pool: pool_stats(&pool.write),
+        read_pool: pool_stats(&pool.read),
+    })
+}
+
+fn pool_stats(pool: &Pool<Sqlite>) -> PoolStats {
+    PoolStats {
+        size: pool.size(),
+        idle: pool.num_idle(),
+        max_connections: pool.options().get_max_connections(),
+    }
+}
@@ -0,0 +1,160 @@
+use std::collections::HashMap;
+
+use axum::{
+    async_trait,
+    extract::{FromRequest, Request},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use utoipa::openapi::{
+    schema::{AdditionalProperties, ArrayItems, Schema},
+    RefOr,
+};
+use utoipa::ToSchema;
+use crate::api::AppState;
+use crate::error::AppError;
+
+/// Request body extractor. Behaves exactly like `Json` unless STRICT_JSON is on, in which case
+/// a field the request type doesn't declare (say `thresold`) is rejected by name instead of
+/// being silently dropped by serde
+#[derive(Debug)]
+pub struct JsonBody<T>(pub T);
+
+#[async_trait]
+impl<T> FromRequest<AppState> for JsonBody<T>
+where
+    T: DeserializeOwned + ToSchema,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &AppState) -> Result<Self, Self::Rejection> {
+        if !state.config.strict_json {
+            let Json(value) = Json::<T>::from_request(req, state).await.map_err(IntoResponse::into_response)?;
+            return Ok(JsonBody(value));
+        }
+        let Json(value) = Json::<Value>::from_request(req, state).await.map_err(IntoResponse::into_response)?;
+        from_value_strict(value).map(JsonBody).map_err(IntoResponse::into_response)
+    }
+}
+
+/// Deserializes `value` after checking every object key against `T`'s OpenAPI schema. Also
+/// used for bodies that arrive inside another request, such as batch steps
+pub fn from_value_strict<T: DeserializeOwned + ToSchema>(value: Value) -> Result<T, AppError> {
+    let mut components = Vec::new();
+    T::schemas(&mut components);
+    let components: HashMap<String, RefOr<Schema>> = components.into_iter().collect();
+    if let Err(path) = check_fields(&value, &T::schema(), &components, "") {
+        return Err(AppError::InvalidInput(format!("Unknown field `{}`", path)));
+    }
+    serde_json::from_value(value)
+        .map_err(|e| AppError::InvalidInput(format!("Failed to deserialize the JSON body: {}", e)))
+}
+
+/// Err holds the path of the first undeclared field, e.g. `seeds[0].amout`. Schemas that
+/// don't describe an object's fields (free-form JSON, maps) accept any keys
+fn check_fields(
+    value: &Value,
+    schema: &RefOr<Schema>,
+    components: &HashMap<String, RefOr<Schema>>,
+    path: &str,
+) -> Result<(), String> {
+    let schema = match schema {
+        RefOr::T(schema) => schema,
+        RefOr::Ref(reference) => {
+            let name = reference.ref_location.rsplit('/').next().unwrap_or_default();
+            return match components.get(name) {
+                Some(schema) => check_fields(value, schema, components, path),
+                None => Ok(()),
+            };
+        }
+    };
+    match (schema, value) {
+        (Schema::Object(object), Value::Object(fields)) => {
+            if object.properties.is_empty() {
+                if let Some(additional) = &object.additional_properties
+                    && let AdditionalProperties::RefOr(values) = additional.as_ref()
+                {
+                    for (key, field) in fields {
+                        check_fields(field, values, components, &join(path, key))?;
+                    }
+                }
+                return Ok(());
+            }
+            for (key, field) in fields {
+                match object.properties.get(key) {
+                    Some(property) => check_fields(field, property, components, &join(path, key))?,
+                    None => return Err(join(path, key)),
+                }
+            }
+            Ok(())
+        }
+        (Schema::Array(array), Value::Array(items)) => {
+            if let ArrayItems::RefOrSchema(item_schema) = &array.items {
+                for (i, item) in items.iter().enumerate() {
+                    check_fields(item, item_schema, components, &format!("{}[{}]", path, i))?;
+                }
+            }
+            Ok(())
+        }
+        // Option<T> and enums: the value only has to fit one of the alternatives
+        (Schema::OneOf(one_of), _) => check_any(value, &one_of.items, components, path),
+        (Schema::AnyOf(any_of), _) => check_any(value, &any_of.items, components, path),
+        _ => Ok(()),
+    }
+}
+
+fn check_any(
+    value: &Value,
+    alternatives: &[RefOr<Schema>],
+    components: &HashMap<String, RefOr<Schema>>,
+    path: &str,
+) -> Result<(), String> {
+    let mut first_error = None;
+    for alternative in alternatives {
+        match check_fields(value, alternative, components, path) {
+            Ok(()) => return Ok(()),
+            Err(e) => { first_error.get_or_insert(e); }
+        }
+    }
+    first_error.map_or(Ok(()), Err)
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() { key.to_string() } else { format!("{}.{}", path, key) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::proposals::CreateProposalRequest;
+
+    fn proposal_body() -> Value {
+        serde_json::json!({
+            "tx_id": "T1", "lock_root_hash": "L1", "proposer_pkh": "A", "threshold": 2,
+            "raw_tx_json": "{}", "notes_json": "[]", "spend_conditions_json": "[]",
+            "total_input_nicks": 100, "seeds": [{"recipient": "R", "amount_nicks": 50}],
+            "proposer_signed_tx_json": "{}",
+        })
+    }
+
+    #[test]
+    fn strict_bodies_name_the_unknown_field() {
+        assert!(from_value_strict::<CreateProposalRequest>(proposal_body()).is_ok());
+
+        let mut typo = proposal_body();
+        typo["thresold"] = 2.into();
+        match from_value_strict::<CreateProposalRequest>(typo) {
+            Err(AppError::InvalidInput(msg)) => assert_eq!(msg, "Unknown field `thresold`"),
+            other => panic!("expected InvalidInput, got {:?}", other.map(|_| ())),
+        }
+
+        let mut nested = proposal_body();
+        nested["seeds"][0]["amout_nicks"] = 1.into();
+        match from_value_strict::<CreateProposalRequest>(nested) {
+            Err(AppError::InvalidInput(msg)) => assert_eq!(msg, "Unknown field `seeds[0].amout_nicks`"),
+            other => panic!("expected InvalidInput, got {:?}", other.map(|_| ())),
+        }
+    }
+}
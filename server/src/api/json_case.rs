@@ -0,0 +1,94 @@
+use axum::{
+    body::{self, Body},
+    extract::Request,
+    http::{header, HeaderValue},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde_json::{Map, Value};
+use crate::error::AppError;
+
+/// Request header choosing the case of response field names: `snake` (default) or `camel`
+pub const JSON_CASE_HEADER: &str = "x-json-case";
+
+/// Rewrites JSON response bodies with camelCase field names when the client asks for them
+/// with `X-Json-Case: camel`. Values are left alone, so map keys that are data (PKHs,
+/// lock-root hashes) pass through; base58 never contains `_` anyway
+pub async fn negotiate_case(request: Request, next: Next) -> Response {
+    let camel = match request.headers().get(JSON_CASE_HEADER).map(|v| v.to_str()) {
+        None => false,
+        Some(Ok(value)) if value.eq_ignore_ascii_case("snake") => false,
+        Some(Ok(value)) if value.eq_ignore_ascii_case("camel") => true,
+        Some(_) => {
+            return AppError::InvalidInput("X-Json-Case must be snake or camel".to_string()).into_response();
+        }
+    };
+    let mut response = next.run(request).await;
+    response.headers_mut().append(header::VARY, HeaderValue::from_static(JSON_CASE_HEADER));
+    let is_json = response.headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("application/json"));
+    if !camel || !is_json {
+        return response;
+    }
+    
+    // Streamed listings are buffered here; only clients that opt in pay for it
+    let (mut parts, body) = response.into_parts();
+    let bytes = match body::to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(e) => return AppError::Internal(format!("Failed to read response body: {}", e)).into_response(),
+    };
+    let Ok(value) = serde_json::from_slice::<Value>(&bytes) else {
+        return Response::from_parts(parts, Body::from(bytes));
+    };
+    let body = serde_json::to_vec(&camel_case_keys(value)).expect("JSON values always serialize");
+    parts.headers.remove(header::CONTENT_LENGTH);
+    Response::from_parts(parts, Body::from(body))
+}
+
+fn camel_case_keys(value: Value) -> Value {
+    match value {
+        Value::Object(fields) => Value::Object(
+            fields.into_iter().map(|(key, value)| (snake_to_camel(&key), camel_case_keys(value))).collect::<Map<_, _>>()
+        ),
+        Value::Array(items) => Value::Array(items.into_iter().map(camel_case_keys).collect()),
+        other => other,
+    }
+}
+
+/// `total_paid_nicks` -> `totalPaidNicks`; a leading underscore is kept
+fn snake_to_camel(key: &str) -> String {
+    let mut out = String::with_capacity(key.len());
+    let mut upper_next = false;
+    for (i, c) in key.chars().enumerate() {
+        if c == '_' && i > 0 {
+            upper_next = true;
+        } else if upper_next {
+            out.extend(c.to_uppercase());
+            upper_next = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renames_nested_keys_but_not_values() {
+        let value = serde_json::json!({
+            "lock_root_hash": "L1",
+            "recipients": [{"total_paid_nicks": 5, "last_paid_at": "snake_value"}],
+            "_private": true,
+        });
+        assert_eq!(camel_case_keys(value), serde_json::json!({
+            "lockRootHash": "L1",
+            "recipients": [{"totalPaidNicks": 5, "lastPaidAt": "snake_value"}],
+            "_private": true,
+        }));
+    }
+}
@@ -0,0 +1,94 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request, State},
+    http::Method,
+    middleware::Next,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::{OpenApi, ToSchema};
+use crate::api::AppState;
+use crate::api::json_body::JsonBody;
+use crate::api::admin::AdminAuth;
+use crate::error::{AppError, ErrorBody};
+
+/// Path of the toggle itself, which must stay writable so maintenance can be ended
+const MAINTENANCE_PATH: &str = "/api/maintenance";
+
+/// Runtime read-only switch, seeded from READ_ONLY and flipped by admins without a restart
+#[derive(Clone, Default)]
+pub struct ReadOnlyFlag(Arc<AtomicBool>);
+
+impl ReadOnlyFlag {
+    pub fn new(enabled: bool) -> Self {
+        Self(Arc::new(AtomicBool::new(enabled)))
+    }
+
+    pub fn get(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    fn set(&self, enabled: bool) {
+        self.0.store(enabled, Ordering::Relaxed);
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MaintenanceResponse {
+    pub read_only: bool,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetMaintenanceRequest {
+    pub read_only: bool,
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(get_maintenance, set_maintenance))]
+pub struct MaintenanceApi;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(get_maintenance).put(set_maintenance))
+}
+
+/// Rejects every mutating request with 503 while read-only mode is on; reads keep serving
+pub async fn read_only_guard(State(flag): State<ReadOnlyFlag>, request: Request, next: Next) -> Response {
+    let is_read = matches!(*request.method(), Method::GET | Method::HEAD | Method::OPTIONS);
+    if flag.get() && !is_read && request.uri().path() != MAINTENANCE_PATH {
+        return AppError::ServiceUnavailable(
+            "The server is in read-only maintenance mode; try again later".to_string()
+        ).into_response();
+    }
+    next.run(request).await
+}
+
+/// Lets clients show a maintenance banner
+#[utoipa::path(
+    get, path = "", tag = "maintenance",
+    responses((status = 200, body = MaintenanceResponse))
+)]
+async fn get_maintenance(State(flag): State<ReadOnlyFlag>) -> Json<MaintenanceResponse> {
+    Json(MaintenanceResponse { read_only: flag.get() })
+}
+
+/// Turns read-only maintenance mode on or off until the next restart
+#[utoipa::path(
+    put, path = "", tag = "maintenance",
+    request_body = SetMaintenanceRequest,
+    security(("admin_token" = [])),
+    responses((status = 200, body = MaintenanceResponse), (status = 401, body = ErrorBody))
+)]
+async fn set_maintenance(
+    _admin: AdminAuth,
+    State(flag): State<ReadOnlyFlag>,
+    JsonBody(req): JsonBody<SetMaintenanceRequest>,
+) -> Json<MaintenanceResponse> {
+    flag.set(req.read_only);
+    tracing::warn!(read_only = req.read_only, "Read-only maintenance mode changed");
+    Json(MaintenanceResponse { read_only: req.read_only })
+}
@@ -1,13 +1,112 @@
+pub mod admin;
+pub mod audit;
+pub mod batch;
+pub mod concurrency;
+pub mod feed;
+pub mod health;
+pub mod json_body;
+pub mod json_case;
+pub mod maintenance;
 pub mod multisigs;
+pub mod pagination;
 pub mod proposals;
+pub mod raw_tx_links;
+pub mod streaming;
+pub mod version;
 
-use axum::Router;
+use std::sync::Arc;
+
+use axum::{
+    extract::FromRef,
+    http::{Method, Uri},
+    middleware, Router,
+};
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
+use crate::api::admin::AdminSecurity;
+use crate::api::concurrency::WriteLimit;
+use crate::api::maintenance::ReadOnlyFlag;
+use crate::config::Config;
 use crate::db::DbPool;
+use crate::error::AppError;
+use crate::events::EventSink;
+
+/// Router state; handlers extract the parts they need (`State<DbPool>`, `State<Arc<Config>>`, ...)
+#[derive(Clone)]
+pub struct AppState {
+    pub pool: DbPool,
+    pub config: Arc<Config>,
+    pub events: Arc<dyn EventSink>,
+    pub read_only: ReadOnlyFlag,
+}
+
+impl FromRef<AppState> for DbPool {
+    fn from_ref(state: &AppState) -> Self {
+        state.pool.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<Config> {
+    fn from_ref(state: &AppState) -> Self {
+        state.config.clone()
+    }
+}
 
-pub fn create_router(pool: DbPool) -> Router {
+impl FromRef<AppState> for Arc<dyn EventSink> {
+    fn from_ref(state: &AppState) -> Self {
+        state.events.clone()
+    }
+}
+
+impl FromRef<AppState> for ReadOnlyFlag {
+    fn from_ref(state: &AppState) -> Self {
+        state.read_only.clone()
+    }
+}
+
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Agora Gateway", description = "Multisig wallet coordination API for Nockchain"),
+    nest(
+        (path = "/api/admin", api = admin::AdminApi),
+        (path = "/api/multisigs", api = multisigs::MultisigsApi),
+        (path = "/api/proposals", api = proposals::ProposalsApi),
+        (path = "/api/feed", api = feed::FeedApi),
+        (path = "/api/version", api = version::VersionApi),
+        (path = "/api/health", api = health::HealthApi),
+        (path = "/api/maintenance", api = maintenance::MaintenanceApi),
+        (path = "/api/batch", api = batch::BatchApi),
+    ),
+    modifiers(&AdminSecurity)
+)]
+pub struct ApiDoc;
+
+pub fn create_router(state: AppState) -> Router {
+    let config = &state.config;
+    // Proposal and batch writes share one limit since a batch creates proposals
+    let proposal_writes = WriteLimit::new(config.proposal_write_concurrency, config.write_queue_timeout());
+    let multisig_writes = WriteLimit::new(config.multisig_write_concurrency, config.write_queue_timeout());
+    // A route layer runs after routing, so path parameters are available to it
+    let audit = middleware::from_fn_with_state(state.clone(), audit::record_mutations);
     Router::new()
-        .nest("/api/multisigs", multisigs::router())
-        .nest("/api/proposals", proposals::router())
-        .with_state(pool)
+        .nest("/api/admin", admin::router().route_layer(audit.clone()))
+        .nest("/api/multisigs", multisigs::router().route_layer(audit.clone()).layer(middleware::from_fn_with_state(multisig_writes, concurrency::limit_writes)))
+        .nest("/api/proposals", proposals::router().route_layer(audit.clone()).layer(middleware::from_fn_with_state(proposal_writes.clone(), concurrency::limit_writes)))
+        .nest("/api/feed", feed::router())
+        .nest("/api/version", version::router())
+        .nest("/api/health", health::router())
+        .nest("/api/maintenance", maintenance::router().route_layer(audit.clone()))
+        .nest("/api/batch", batch::router().route_layer(audit).layer(middleware::from_fn_with_state(proposal_writes, concurrency::limit_writes)))
+        // Only the API routes above; the OpenAPI document keeps its own field names
+        .layer(middleware::from_fn(json_case::negotiate_case))
+        .merge(SwaggerUi::new("/api/docs").url("/api/openapi.json", ApiDoc::openapi()))
+        .fallback(route_not_found)
+        .layer(middleware::from_fn_with_state(state.clone(), maintenance::read_only_guard))
+        .with_state(state)
 }
 
+/// Unknown paths (including under a nested prefix) get the standard error envelope
+/// instead of axum's empty 404
+async fn route_not_found(method: Method, uri: Uri) -> AppError {
+    AppError::NotFound(format!("route not found: {} {}", method, uri.path()))
+}
@@ -1,56 +1,435 @@
 use axum::{
-    extract::{Query, State},
-    routing::{post},
+    extract::{Path, Query, State},
+    http::HeaderMap,
+    response::Response,
+    routing::{delete, get, patch, post, put},
     Json, Router,
 };
+use axum_extra::extract::Query as FormQuery;
 use serde::{Deserialize, Serialize};
-use crate::db::{DbPool, Lock, LockParticipant};
-use crate::error::AppError;
+use utoipa::{IntoParams, OpenApi, ToSchema};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use sqlx::{Connection, Pool, Sqlite, SqliteConnection};
+use uuid::Uuid;
+use crate::api::AppState;
+use crate::api::json_body::JsonBody;
+use crate::api::admin::AdminAuth;
+use crate::api::proposals::{self, validate_json_field, ListProposalsQuery, ProposalResponse};
+use crate::config::Config;
+use crate::db::{canonicalize_rfc3339, format_rfc3339, now_rfc3339, rfc3339_secs_from_now, DbPool, Delegation, LockParticipant, LockRootHash, Pkh, LockWithActivity, ProposalStatus, RecipientTotalRow, SharedParticipantRow, SignerStatRow};
+use crate::error::{AppError, ErrorBody};
+use crate::events::check_webhook_target;
+use crate::validation::validate_multisig_invariants;
 
-#[derive(Debug, Deserialize)]
-struct CreateMultisigRequest {
-    lock_root_hash: String, // Base58-encoded lock-root hash (firstName) computed on client
-    threshold: i32,
-    total_signers: i32,
-    signer_pkhs: Vec<String>, 
-    created_by_pkh: String,
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateMultisigRequest {
+    pub lock_root_hash: LockRootHash, // Base58-encoded lock-root hash (firstName) computed on client
+    pub threshold: i32,
+    pub total_signers: i32,
+    pub signer_pkhs: Vec<String>, 
+    pub created_by_pkh: Pkh,
+    /// Optional JSON document, e.g. {"name": "Treasury", "tags": ["cold"]}
+    #[serde(default)]
+    pub metadata_json: Option<String>,
+    /// Proposals must collect their signatures within this many seconds of creation
+    #[serde(default)]
+    pub signing_window_secs: Option<i64>,
+    /// Participants (from signer_pkhs) whose signature every proposal needs on top of the threshold
+    #[serde(default)]
+    pub required_signers: Vec<Pkh>,
 }
 
-#[derive(Debug, Serialize)]
-struct CreateMultisigResponse {
-    lock_root_hash: String,
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CreateMultisigResponse {
+    pub lock_root_hash: LockRootHash,
 }
 
-#[derive(Debug, Deserialize)]
-struct ListMultisigsQuery {
-    pkh: Option<String>,
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BatchCreateMultisigResult {
+    pub lock_root_hash: LockRootHash,
+    pub success: bool,
+    pub error: Option<String>, // Why the item was rejected
 }
 
-#[derive(Debug, Serialize)]
-struct MultisigResponse {
-    lock_root_hash: String, 
-    threshold: i32,
-    total_signers: i32,
-    created_at: String,
-    created_by_pkh: String,
-    participants: Vec<String>, 
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BatchCreateMultisigResponse {
+    pub results: Vec<BatchCreateMultisigResult>, // Same order as the request
 }
 
-pub fn router() -> Router<DbPool> {
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListMultisigsQuery {
+    pub pkh: Option<Pkh>,
+    pub sort: Option<String>, // "created_at" or "last_activity_at" (both newest first)
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MultisigResponse {
+    pub lock_root_hash: LockRootHash, 
+    pub threshold: i32,
+    pub total_signers: i32,
+    pub created_at: String,
+    pub created_by_pkh: Pkh,
+    pub last_activity_at: String,
+    pub participants: Vec<Pkh>, 
+    pub inactive_participants: Vec<Pkh>, // Advisory: co-signers marked unavailable
+    pub required_signers: Vec<Pkh>, // Must sign every proposal in addition to the threshold
+    pub metadata_json: Option<String>,
+    pub signing_window_secs: Option<i64>,
+    /// Sum of total_input_nicks over pending and ready proposals spending from this wallet.
+    /// A multi-wallet proposal counts in full for each wallet, since the split isn't stored
+    pub pending_value_nicks: i64,
+    pub pending_value_display: String,
+    pub proposal_velocity: ProposalVelocity,
+}
+
+/// Recent proposal rate against the wallet's own baseline; drafts are not counted
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ProposalVelocity {
+    pub last_24h: i64,
+    /// Per day over the ANOMALY_BASELINE_DAYS before the last 24h
+    pub daily_average: f64,
+    /// last_24h exceeds ANOMALY_VELOCITY_MULTIPLE times daily_average, with the average taken
+    /// as at least one a day so a quiet wallet's first proposals aren't flagged
+    pub anomalous: bool,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AddAllowedRecipientRequest {
+    pub recipient: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AllowedRecipientsResponse {
+    pub lock_root_hash: LockRootHash,
+    pub recipients: Vec<String>, // Empty means any recipient is allowed
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateParticipantRequest {
+    /// Participant making the change; not needed with an admin bearer token
+    pub actor_pkh: Option<Pkh>,
+    pub active: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ParticipantResponse {
+    pub lock_root_hash: LockRootHash,
+    pub pkh: Pkh,
+    pub active: bool,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateMetadataRequest {
+    pub pkh: Pkh,
+    /// Replaces the stored metadata; null clears it
+    pub metadata_json: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UpdateMetadataResponse {
+    pub lock_root_hash: LockRootHash,
+    pub metadata_json: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateSigningWindowRequest {
+    pub pkh: Pkh,
+    /// Replaces the wallet's window; null removes it. Only affects proposals created afterwards
+    pub signing_window_secs: Option<i64>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UpdateSigningWindowResponse {
+    pub lock_root_hash: LockRootHash,
+    pub signing_window_secs: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ExpireOpenRequest {
+    /// Participant making the change; not needed with an admin bearer token
+    pub actor_pkh: Option<Pkh>,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ExpireOpenResponse {
+    pub lock_root_hash: LockRootHash,
+    pub expired: u64, // Pending/ready proposals moved to expired
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetWebhookRequest {
+    pub pkh: Pkh,
+    pub url: String,            // http(s) endpoint receiving this wallet's events
+    pub secret: Option<String>, // Signs deliveries via X-Agora-Signature; omit for unsigned
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ClearWebhookRequest {
+    pub pkh: Pkh,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookResponse {
+    pub lock_root_hash: LockRootHash,
+    /// None when the wallet's events go to the server-wide sink
+    pub webhook_url: Option<String>,
+    pub has_secret: bool, // The secret itself is never returned
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ParticipantLookupRequest {
+    pub pkhs: Vec<Pkh>, // At most MAX_LOOKUP_PKHS
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ParticipantLookupResponse {
+    /// Every requested PKH mapped to the wallets it participates in (empty if none)
+    pub wallets: BTreeMap<Pkh, Vec<LockRootHash>>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct CreateDelegationRequest {
+    pub from_pkh: Pkh,   // Participant handing over signing authority
+    pub to_pkh: Pkh,     // Participant who may sign on their behalf
+    pub expires_at: String, // RFC 3339; must be in the future
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct RevokeDelegationRequest {
+    pub from_pkh: Pkh, // Only the delegating participant may revoke
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DelegationResponse {
+    pub id: String,
+    pub lock_root_hash: LockRootHash,
+    pub from_pkh: Pkh,
+    pub to_pkh: Pkh,
+    pub expires_at: String,
+    pub created_at: String,
+    pub revoked_at: Option<String>,
+}
+
+impl From<Delegation> for DelegationResponse {
+    fn from(d: Delegation) -> Self {
+        Self {
+            id: d.id,
+            lock_root_hash: d.lock_root_hash,
+            from_pkh: d.from_pkh,
+            to_pkh: d.to_pkh,
+            expires_at: d.expires_at,
+            created_at: d.created_at,
+            revoked_at: d.revoked_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SignerStat {
+    pub pkh: Pkh,
+    pub signatures: i64, // Open proposals and history transactions this participant signed
+    pub last_signed_at: Option<String>, // Signing time, or broadcast time for history
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SignerStatsResponse {
+    pub lock_root_hash: LockRootHash,
+    pub signers: Vec<SignerStat>, // Most active first
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PaidRecipient {
+    pub recipient: String,
+    pub payments: i64, // History transactions with at least one seed to this recipient
+    pub total_paid_nicks: i64,
+    pub total_paid_display: String,
+    pub last_paid_at: String, // Broadcast time, or record time when never broadcast
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PaidRecipientsResponse {
+    pub lock_root_hash: LockRootHash,
+    pub recipients: Vec<PaidRecipient>, // Most recently paid first
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RelatedWallet {
+    pub lock_root_hash: LockRootHash,
+    pub threshold: i32,
+    pub total_signers: i32,
+    pub created_at: String,
+    pub metadata_json: Option<String>,
+    pub shared_participants: Vec<Pkh>, // Participants of the queried wallet also in this one
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RelatedWalletsResponse {
+    pub lock_root_hash: LockRootHash,
+    pub wallets: Vec<RelatedWallet>, // Most shared participants first
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(create_multisig, create_multisig_batch, list_multisigs, lookup_participants, update_metadata, update_signing_window, set_webhook, clear_webhook, update_participant, list_allowed_recipients, add_allowed_recipient, remove_allowed_recipient, list_delegations, create_delegation, revoke_delegation, list_wallet_proposals, expire_open_proposals, signer_stats, list_paid_recipients, list_related_wallets))]
+pub struct MultisigsApi;
+
+pub fn router() -> Router<AppState> {
     Router::new()
         .route("/", post(create_multisig).get(list_multisigs))
+        .route("/batch", post(create_multisig_batch))
+        .route("/participants/lookup", post(lookup_participants))
+        .route("/:lock_root_hash/metadata", patch(update_metadata))
+        .route("/:lock_root_hash/signing-window", put(update_signing_window))
+        .route("/:lock_root_hash/webhook", put(set_webhook).delete(clear_webhook))
+        .route("/:lock_root_hash/participants/:pkh", patch(update_participant))
+        .route("/:lock_root_hash/allowed-recipients", get(list_allowed_recipients).post(add_allowed_recipient))
+        .route("/:lock_root_hash/allowed-recipients/:recipient", delete(remove_allowed_recipient))
+        .route("/:lock_root_hash/delegations", get(list_delegations).post(create_delegation))
+        .route("/:lock_root_hash/delegations/:id", delete(revoke_delegation))
+        .route("/:lock_root_hash/proposals", get(list_wallet_proposals))
+        .route("/:lock_root_hash/expire-open", post(expire_open_proposals))
+        .route("/:lock_root_hash/signer-stats", get(signer_stats))
+        .route("/:lock_root_hash/recipients", get(list_paid_recipients))
+        .route("/:lock_root_hash/related", get(list_related_wallets))
 }
 
+#[utoipa::path(
+    post, path = "", tag = "multisigs",
+    request_body = CreateMultisigRequest,
+    responses((status = 200, body = CreateMultisigResponse), (status = 400, body = ErrorBody))
+)]
 async fn create_multisig(
     State(pool): State<DbPool>,
-    Json(req): Json<CreateMultisigRequest>,
+    State(config): State<Arc<Config>>,
+    JsonBody(req): JsonBody<CreateMultisigRequest>,
 ) -> Result<Json<CreateMultisigResponse>, AppError> {
+    let mut tx = pool.write.begin().await?;
+    insert_multisig(&mut tx, &config, &req).await?;
+    tx.commit().await?;
+    
+    Ok(Json(CreateMultisigResponse {
+        lock_root_hash: req.lock_root_hash,
+    }))
+}
+
+/// Onboarding import: inserts every multisig in one transaction, reporting per-item failures
+/// (duplicates, invalid input) instead of aborting the whole batch
+#[utoipa::path(
+    post, path = "/batch", tag = "multisigs",
+    request_body = Vec<CreateMultisigRequest>,
+    responses((status = 200, body = BatchCreateMultisigResponse), (status = 400, body = ErrorBody))
+)]
+async fn create_multisig_batch(
+    State(pool): State<DbPool>,
+    State(config): State<Arc<Config>>,
+    JsonBody(requests): JsonBody<Vec<CreateMultisigRequest>>,
+) -> Result<Json<BatchCreateMultisigResponse>, AppError> {
+    let mut results = Vec::with_capacity(requests.len());
+    let mut tx = pool.write.begin().await?;
+    for req in &requests {
+        // Each item runs in a savepoint so a rejected one leaves no partial rows behind
+        let mut item_tx = tx.begin().await?;
+        match insert_multisig(&mut item_tx, &config, req).await {
+            Ok(()) => {
+                item_tx.commit().await?;
+                results.push(BatchCreateMultisigResult {
+                    lock_root_hash: req.lock_root_hash.clone(),
+                    success: true,
+                    error: None,
+                });
+            }
+            Err(AppError::InvalidInput(reason)) => {
+                item_tx.rollback().await?;
+                results.push(BatchCreateMultisigResult {
+                    lock_root_hash: req.lock_root_hash.clone(),
+                    success: false,
+                    error: Some(reason),
+                });
+            }
+            Err(e) => return Err(e),
+        }
+    }
+    tx.commit().await?;
+    
+    let created = results.iter().filter(|r| r.success).count();
+    tracing::info!(requested = requests.len(), created, "Multisig batch import");
+    
+    Ok(Json(BatchCreateMultisigResponse { results }))
+}
+
+/// Directory view: which wallets each of a set of PKHs participates in, in one query
+#[utoipa::path(
+    post, path = "/participants/lookup", tag = "multisigs",
+    request_body = ParticipantLookupRequest,
+    responses((status = 200, body = ParticipantLookupResponse), (status = 400, body = ErrorBody))
+)]
+async fn lookup_participants(
+    State(pool): State<DbPool>,
+    State(config): State<Arc<Config>>,
+    JsonBody(req): JsonBody<ParticipantLookupRequest>,
+) -> Result<Json<ParticipantLookupResponse>, AppError> {
+    if req.pkhs.len() > config.max_lookup_pkhs {
+        return Err(AppError::InvalidInput(
+            format!("Too many PKHs ({}, max {})", req.pkhs.len(), config.max_lookup_pkhs)
+        ));
+    }
+    
+    let mut wallets: BTreeMap<Pkh, Vec<LockRootHash>> = req.pkhs.iter()
+        .map(|pkh| (pkh.clone(), Vec::new()))
+        .collect();
+    if wallets.is_empty() {
+        return Ok(Json(ParticipantLookupResponse { wallets }));
+    }
+    
+    let placeholders = wallets.keys().map(|_| "?").collect::<Vec<_>>().join(",");
+    let query = format!(
+        "SELECT lock_root_hash, pkh, active, required FROM lock_participants WHERE pkh IN ({}) ORDER BY lock_root_hash",
+        placeholders
+    );
+    let mut query_builder = sqlx::query_as::<_, LockParticipant>(&query);
+    for pkh in wallets.keys() {
+        query_builder = query_builder.bind(pkh);
+    }
+    let rows: Vec<LockParticipant> = query_builder.fetch_all(&pool.read).await?;
+    
+    for row in rows {
+        if let Some(locks) = wallets.get_mut(&row.pkh) {
+            locks.push(row.lock_root_hash);
+        }
+    }
+    
+    Ok(Json(ParticipantLookupResponse { wallets }))
+}
+
+/// Validates one multisig and inserts the lock plus its participants
+pub(crate) async fn insert_multisig(
+    conn: &mut SqliteConnection,
+    config: &Config,
+    req: &CreateMultisigRequest,
+) -> Result<(), AppError> {
+    // Bound participant rows and the size of the participant IN (...) queries
+    let signer_count = (req.total_signers.max(0) as usize).max(req.signer_pkhs.len());
+    if signer_count > config.max_signers {
+        return Err(AppError::InvalidInput(
+            format!("A multisig can have at most {} signers", config.max_signers)
+        ));
+    }
+    if let Some(metadata_json) = &req.metadata_json {
+        validate_json_field("metadata_json", metadata_json, config.max_metadata_json_bytes)?;
+    }
+    validate_signing_window(req.signing_window_secs)?;
+    validate_multisig_invariants(req.threshold, req.total_signers, &req.signer_pkhs, &req.required_signers).map_err(|violations| {
+        AppError::InvalidInput(violations.iter().map(ToString::to_string).collect::<Vec<_>>().join("; "))
+    })?;
+    
     // Check if a multisig with this lock_root_hash already exists
     let existing: Option<String> = sqlx::query_scalar(
         "SELECT lock_root_hash FROM locks WHERE lock_root_hash = ? LIMIT 1"
     )
     .bind(&req.lock_root_hash)
-    .fetch_optional(&pool)
+    .fetch_optional(&mut *conn)
     .await?;
     
     if existing.is_some() {
@@ -61,64 +440,118 @@ async fn create_multisig(
     
     // insert multisig spending condition 
     sqlx::query(
-        "INSERT INTO locks (lock_root_hash, threshold, total_signers, created_at, created_by_pkh) VALUES (?, ?, ?, ?, ?)"
+        "INSERT INTO locks (lock_root_hash, threshold, total_signers, created_at, created_by_pkh, metadata_json, signing_window_secs)
+         VALUES (?, ?, ?, ?, ?, ?, ?)"
     )
     .bind(&req.lock_root_hash)
     .bind(req.threshold)
     .bind(req.total_signers)
-    .bind(chrono::Utc::now().to_rfc3339())
+    .bind(now_rfc3339())
     .bind(&req.created_by_pkh)
-    .execute(&pool)
+    .bind(&req.metadata_json)
+    .bind(req.signing_window_secs)
+    .execute(&mut *conn)
     .await?;
     
     // insert multisig wallet participants
     for pkh in &req.signer_pkhs {
         sqlx::query(
-            "INSERT INTO lock_participants (lock_root_hash, pkh) VALUES (?, ?)"
+            "INSERT INTO lock_participants (lock_root_hash, pkh, required) VALUES (?, ?, ?)"
         )
         .bind(&req.lock_root_hash)
         .bind(pkh)
-        .execute(&pool)
+        .bind(req.required_signers.iter().any(|required| required == pkh.as_str()))
+        .execute(&mut *conn)
         .await?;
     }
     
-    Ok(Json(CreateMultisigResponse {
-        lock_root_hash: req.lock_root_hash,
-    }))
+    Ok(())
 }
 
+#[utoipa::path(
+    get, path = "", tag = "multisigs",
+    params(ListMultisigsQuery),
+    responses((status = 200, body = Vec<MultisigResponse>), (status = 400, body = ErrorBody))
+)]
 async fn list_multisigs(
     State(pool): State<DbPool>,
+    State(config): State<Arc<Config>>,
     Query(params): Query<ListMultisigsQuery>,
 ) -> Result<Json<Vec<MultisigResponse>>, AppError> {
-    let locks: Vec<Lock> = if let Some(pkh) = params.pkh {
+    let order_by = match params.sort.as_deref() {
+        None => "",
+        Some("created_at") => " ORDER BY l.created_at DESC",
+        Some("last_activity_at") => " ORDER BY last_activity_at DESC",
+        Some(other) => {
+            return Err(AppError::InvalidInput(format!(
+                "Invalid sort: {} (expected created_at or last_activity_at)", other
+            )));
+        }
+    };
+    let filter = if params.pkh.is_some() {
         // Get multisigs where this PKH is a participant
-        sqlx::query_as::<_, Lock>(
-            "SELECT DISTINCT l.lock_root_hash, l.threshold, l.total_signers, l.created_at, l.created_by_pkh 
-             FROM locks l 
-             INNER JOIN lock_participants lp ON l.lock_root_hash = lp.lock_root_hash 
-             WHERE lp.pkh = ?"
-        )
-        .bind(pkh)
-        .fetch_all(&pool)
-        .await?
+        " WHERE l.lock_root_hash IN (SELECT lock_root_hash FROM lock_participants WHERE pkh = ?)"
     } else {
-        sqlx::query_as::<_, Lock>(
-            "SELECT lock_root_hash, threshold, total_signers, created_at, created_by_pkh FROM locks"
-        )
-        .fetch_all(&pool)
-        .await?
+        ""
     };
     
+    // Last activity is the newest of proposal updated_at (any proposal spending from the wallet)
+    // / history broadcast_at, else created_at
+    let query = format!(
+        "SELECT l.lock_root_hash, l.threshold, l.total_signers, l.created_at, l.created_by_pkh, l.metadata_json, l.signing_window_secs,
+                COALESCE(MAX(pa.ts, ha.ts), pa.ts, ha.ts, l.created_at) AS last_activity_at,
+                COALESCE(pv.nicks, 0) AS pending_value_nicks,
+                COALESCE(pc.recent, 0) AS proposals_last_24h,
+                COALESCE(pc.baseline, 0) AS proposals_baseline
+         FROM locks l
+         LEFT JOIN (SELECT w.lock_root_hash, MAX(p.updated_at) AS ts
+                    FROM (SELECT id AS proposal_id, lock_root_hash FROM proposals
+                          UNION SELECT proposal_id, lock_root_hash FROM proposal_locks) w
+                    INNER JOIN proposals p ON p.id = w.proposal_id
+                    GROUP BY w.lock_root_hash) pa
+           ON pa.lock_root_hash = l.lock_root_hash
+         LEFT JOIN (SELECT lock_root_hash, MAX(broadcast_at) AS ts FROM transaction_history GROUP BY lock_root_hash) ha
+           ON ha.lock_root_hash = l.lock_root_hash
+         LEFT JOIN (SELECT w.lock_root_hash, SUM(p.total_input_nicks) AS nicks
+                    FROM (SELECT id AS proposal_id, lock_root_hash FROM proposals
+                          UNION SELECT proposal_id, lock_root_hash FROM proposal_locks) w
+                    INNER JOIN proposals p ON p.id = w.proposal_id
+                    WHERE p.status IN ('pending', 'ready')
+                    GROUP BY w.lock_root_hash) pv
+           ON pv.lock_root_hash = l.lock_root_hash
+         LEFT JOIN (SELECT w.lock_root_hash,
+                           SUM(p.created_at >= ?) AS recent,
+                           SUM(p.created_at < ?) AS baseline
+                    FROM (SELECT id AS proposal_id, lock_root_hash FROM proposals
+                          UNION SELECT proposal_id, lock_root_hash FROM proposal_locks) w
+                    INNER JOIN proposals p ON p.id = w.proposal_id
+                    WHERE p.status != 'draft' AND p.created_at >= ?
+                    GROUP BY w.lock_root_hash) pc
+           ON pc.lock_root_hash = l.lock_root_hash{}{}",
+        filter, order_by
+    );
+    
+    let now = chrono::Utc::now();
+    let recent_since = format_rfc3339(now - chrono::Duration::hours(24));
+    let baseline_since = format_rfc3339(now - chrono::Duration::hours(24) - chrono::Duration::days(config.anomaly_baseline_days.into()));
+    let mut locks_query = sqlx::query_as::<_, LockWithActivity>(&query)
+        .bind(&recent_since)
+        .bind(&recent_since)
+        .bind(&baseline_since);
+    if let Some(pkh) = &params.pkh {
+        locks_query = locks_query.bind(pkh);
+    }
+    let locks: Vec<LockWithActivity> = locks_query.fetch_all(&pool.read).await?;
+    
     if locks.is_empty() {
         return Ok(Json(vec![]));
     }
     
     // Fetch all participants for the retrieved locks in a single query
-    let lock_hashes: Vec<&str> = locks.iter().map(|l| l.lock_root_hash.as_str()).collect();
+    let lock_hashes: Vec<&str> = locks.iter().map(|l| l.lock.lock_root_hash.as_str()).collect();
     let placeholders = lock_hashes.iter().map(|_| "?").collect::<Vec<_>>().join(",");
     let query = format!(
-        "SELECT lock_root_hash, pkh FROM lock_participants WHERE lock_root_hash IN ({})",
+        "SELECT lock_root_hash, pkh, active, required FROM lock_participants WHERE lock_root_hash IN ({}) ORDER BY pkh",
         placeholders
     );
     
@@ -126,26 +559,874 @@ async fn list_multisigs(
     for hash in &lock_hashes {
         query_builder = query_builder.bind(*hash);
     }
-    let all_participants: Vec<LockParticipant> = query_builder.fetch_all(&pool).await?;
+    let all_participants: Vec<LockParticipant> = query_builder.fetch_all(&pool.read).await?;
     
     // Group participants by lock_root_hash
-    let mut participants_map: std::collections::HashMap<String, Vec<String>> = std::collections::HashMap::new();
+    let mut participants_map: std::collections::HashMap<LockRootHash, Vec<LockParticipant>> = std::collections::HashMap::new();
     for p in all_participants {
-        participants_map.entry(p.lock_root_hash).or_default().push(p.pkh);
+        participants_map.entry(p.lock_root_hash.clone()).or_default().push(p);
     }
     
-    let response: Vec<MultisigResponse> = locks.into_iter().map(|lock| {
+    let response: Vec<MultisigResponse> = locks.into_iter().map(|row| {
+        let lock = row.lock;
         let participants = participants_map.remove(&lock.lock_root_hash).unwrap_or_default();
+        let inactive_participants = participants.iter()
+            .filter(|p| !p.active)
+            .map(|p| p.pkh.clone())
+            .collect();
+        let required_signers = participants.iter()
+            .filter(|p| p.required)
+            .map(|p| p.pkh.clone())
+            .collect();
         MultisigResponse {
             lock_root_hash: lock.lock_root_hash,
             threshold: lock.threshold,
             total_signers: lock.total_signers,
             created_at: lock.created_at,
             created_by_pkh: lock.created_by_pkh,
-            participants,
+            last_activity_at: row.last_activity_at,
+            participants: participants.into_iter().map(|p| p.pkh).collect(),
+            inactive_participants,
+            required_signers,
+            metadata_json: lock.metadata_json,
+            signing_window_secs: lock.signing_window_secs,
+            pending_value_nicks: row.pending_value_nicks,
+            pending_value_display: config.format_nicks(row.pending_value_nicks),
+            proposal_velocity: proposal_velocity(row.proposals_last_24h, row.proposals_baseline, &config),
         }
     }).collect();
     
     Ok(Json(response))
 }
 
+#[utoipa::path(
+    patch, path = "/{lock_root_hash}/metadata", tag = "multisigs",
+    params(("lock_root_hash" = String, Path, description = "Wallet lock-root hash")),
+    request_body = UpdateMetadataRequest,
+    responses(
+        (status = 200, body = UpdateMetadataResponse),
+        (status = 400, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    )
+)]
+async fn update_metadata(
+    State(pool): State<DbPool>,
+    State(config): State<Arc<Config>>,
+    Path(lock_root_hash): Path<LockRootHash>,
+    JsonBody(req): JsonBody<UpdateMetadataRequest>,
+) -> Result<Json<UpdateMetadataResponse>, AppError> {
+    if let Some(metadata_json) = &req.metadata_json {
+        validate_json_field("metadata_json", metadata_json, config.max_metadata_json_bytes)?;
+    }
+    
+    let lock_exists: Option<i32> = sqlx::query_scalar(
+        "SELECT 1 FROM locks WHERE lock_root_hash = ? LIMIT 1"
+    )
+    .bind(&lock_root_hash)
+    .fetch_optional(&pool.write)
+    .await?;
+    
+    if lock_exists.is_none() {
+        return Err(AppError::NotFound(
+            format!("Wallet with lock_root_hash {} not found", lock_root_hash)
+        ));
+    }
+    
+    // Only wallet participants may edit metadata
+    let is_participant: Option<i32> = sqlx::query_scalar(
+        "SELECT 1 FROM lock_participants WHERE lock_root_hash = ? AND pkh = ?"
+    )
+    .bind(&lock_root_hash)
+    .bind(&req.pkh)
+    .fetch_optional(&pool.write)
+    .await?;
+    
+    if is_participant.is_none() {
+        return Err(AppError::InvalidInput(
+            format!("PKH {} is not a participant of this wallet", req.pkh)
+        ));
+    }
+    
+    sqlx::query("UPDATE locks SET metadata_json = ? WHERE lock_root_hash = ?")
+        .bind(&req.metadata_json)
+        .bind(&lock_root_hash)
+        .execute(&pool.write)
+        .await?;
+    
+    tracing::info!(lock_root_hash = %lock_root_hash, pkh = %req.pkh, "Multisig metadata updated");
+    
+    Ok(Json(UpdateMetadataResponse {
+        lock_root_hash,
+        metadata_json: req.metadata_json,
+    }))
+}
+
+const MAX_WEBHOOK_URL_LEN: usize = 2048;
+
+const MAX_SIGNING_WINDOW_SECS: i64 = 365 * 24 * 60 * 60;
+
+fn validate_signing_window(signing_window_secs: Option<i64>) -> Result<(), AppError> {
+    match signing_window_secs {
+        Some(secs) if !(1..=MAX_SIGNING_WINDOW_SECS).contains(&secs) => Err(AppError::InvalidInput(
+            format!("signing_window_secs must be between 1 and {}", MAX_SIGNING_WINDOW_SECS)
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Sets how long proposals from this wallet stay open for signing. Existing proposals keep
+/// the deadline they were created with
+#[utoipa::path(
+    put, path = "/{lock_root_hash}/signing-window", tag = "multisigs",
+    params(("lock_root_hash" = String, Path, description = "Wallet lock-root hash")),
+    request_body = UpdateSigningWindowRequest,
+    responses(
+        (status = 200, body = UpdateSigningWindowResponse),
+        (status = 400, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    )
+)]
+async fn update_signing_window(
+    State(pool): State<DbPool>,
+    Path(lock_root_hash): Path<LockRootHash>,
+    JsonBody(req): JsonBody<UpdateSigningWindowRequest>,
+) -> Result<Json<UpdateSigningWindowResponse>, AppError> {
+    validate_signing_window(req.signing_window_secs)?;
+    ensure_lock_exists(&pool.write, &lock_root_hash).await?;
+    ensure_participant(&pool.write, &lock_root_hash, &req.pkh).await?;
+    
+    sqlx::query("UPDATE locks SET signing_window_secs = ? WHERE lock_root_hash = ?")
+        .bind(req.signing_window_secs)
+        .bind(&lock_root_hash)
+        .execute(&pool.write)
+        .await?;
+    
+    tracing::info!(
+        lock_root_hash = %lock_root_hash,
+        pkh = %req.pkh,
+        signing_window_secs = ?req.signing_window_secs,
+        "Signing window updated"
+    );
+    Ok(Json(UpdateSigningWindowResponse {
+        lock_root_hash,
+        signing_window_secs: req.signing_window_secs,
+    }))
+}
+
+/// Routes this wallet's proposal events to its own webhook instead of the server-wide sink
+#[utoipa::path(
+    put, path = "/{lock_root_hash}/webhook", tag = "multisigs",
+    params(("lock_root_hash" = String, Path, description = "Wallet lock-root hash")),
+    request_body = SetWebhookRequest,
+    responses(
+        (status = 200, body = WebhookResponse),
+        (status = 400, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    )
+)]
+async fn set_webhook(
+    State(pool): State<DbPool>,
+    State(config): State<Arc<Config>>,
+    Path(lock_root_hash): Path<LockRootHash>,
+    JsonBody(req): JsonBody<SetWebhookRequest>,
+) -> Result<Json<WebhookResponse>, AppError> {
+    if req.url.len() > MAX_WEBHOOK_URL_LEN {
+        return Err(AppError::InvalidInput(format!("url exceeds {} bytes", MAX_WEBHOOK_URL_LEN)));
+    }
+    let url = reqwest::Url::parse(&req.url)
+        .map_err(|e| AppError::InvalidInput(format!("Invalid webhook url: {}", e)))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(AppError::InvalidInput(format!("Webhook url must be http or https: {}", req.url)));
+    }
+    if !config.webhook_allow_private_targets {
+        check_webhook_target(&url)
+            .await
+            .map_err(|e| AppError::InvalidInput(format!("Webhook url is not allowed: {}", e)))?;
+    }
+    if req.secret.as_deref().is_some_and(str::is_empty) {
+        return Err(AppError::InvalidInput("secret must not be empty; omit it for unsigned deliveries".to_string()));
+    }
+    
+    ensure_lock_exists(&pool.write, &lock_root_hash).await?;
+    ensure_participant(&pool.write, &lock_root_hash, &req.pkh).await?;
+    
+    sqlx::query("UPDATE locks SET webhook_url = ?, webhook_secret = ? WHERE lock_root_hash = ?")
+        .bind(&req.url)
+        .bind(&req.secret)
+        .bind(&lock_root_hash)
+        .execute(&pool.write)
+        .await?;
+    
+    tracing::info!(lock_root_hash = %lock_root_hash, pkh = %req.pkh, url = %req.url, "Wallet webhook set");
+    Ok(Json(WebhookResponse {
+        lock_root_hash,
+        webhook_url: Some(req.url),
+        has_secret: req.secret.is_some(),
+    }))
+}
+
+/// Sends this wallet's events back to the server-wide sink
+#[utoipa::path(
+    delete, path = "/{lock_root_hash}/webhook", tag = "multisigs",
+    params(("lock_root_hash" = String, Path, description = "Wallet lock-root hash")),
+    request_body = ClearWebhookRequest,
+    responses(
+        (status = 200, body = WebhookResponse),
+        (status = 400, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    )
+)]
+async fn clear_webhook(
+    State(pool): State<DbPool>,
+    Path(lock_root_hash): Path<LockRootHash>,
+    JsonBody(req): JsonBody<ClearWebhookRequest>,
+) -> Result<Json<WebhookResponse>, AppError> {
+    ensure_lock_exists(&pool.write, &lock_root_hash).await?;
+    ensure_participant(&pool.write, &lock_root_hash, &req.pkh).await?;
+    
+    sqlx::query("UPDATE locks SET webhook_url = NULL, webhook_secret = NULL WHERE lock_root_hash = ?")
+        .bind(&lock_root_hash)
+        .execute(&pool.write)
+        .await?;
+    
+    tracing::info!(lock_root_hash = %lock_root_hash, pkh = %req.pkh, "Wallet webhook cleared");
+    Ok(Json(WebhookResponse { lock_root_hash, webhook_url: None, has_secret: false }))
+}
+
+/// Marks a co-signer available or unavailable. Advisory only: it feeds reachability hints
+/// and never changes the wallet's threshold
+#[utoipa::path(
+    patch, path = "/{lock_root_hash}/participants/{pkh}", tag = "multisigs",
+    params(
+        ("lock_root_hash" = String, Path, description = "Wallet lock-root hash"),
+        ("pkh" = String, Path, description = "Participant PKH"),
+    ),
+    request_body = UpdateParticipantRequest,
+    security((), ("admin_token" = [])),
+    responses(
+        (status = 200, body = ParticipantResponse),
+        (status = 400, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    )
+)]
+async fn update_participant(
+    admin: Option<AdminAuth>,
+    State(pool): State<DbPool>,
+    Path((lock_root_hash, pkh)): Path<(LockRootHash, Pkh)>,
+    JsonBody(req): JsonBody<UpdateParticipantRequest>,
+) -> Result<Json<ParticipantResponse>, AppError> {
+    let is_target: Option<i32> = sqlx::query_scalar(
+        "SELECT 1 FROM lock_participants WHERE lock_root_hash = ? AND pkh = ?"
+    )
+    .bind(&lock_root_hash)
+    .bind(&pkh)
+    .fetch_optional(&pool.write)
+    .await?;
+    
+    if is_target.is_none() {
+        return Err(AppError::NotFound(
+            format!("PKH {} is not a participant of wallet {}", pkh, lock_root_hash)
+        ));
+    }
+    
+    // Without an admin token, only a fellow participant may change availability
+    let actor = if admin.is_some() {
+        "admin".to_string()
+    } else {
+        let actor_pkh = req.actor_pkh.clone().ok_or_else(|| AppError::InvalidInput(
+            "actor_pkh is required without an admin token".to_string()
+        ))?;
+        let is_participant: Option<i32> = sqlx::query_scalar(
+            "SELECT 1 FROM lock_participants WHERE lock_root_hash = ? AND pkh = ?"
+        )
+        .bind(&lock_root_hash)
+        .bind(&actor_pkh)
+        .fetch_optional(&pool.write)
+        .await?;
+        
+        if is_participant.is_none() {
+            return Err(AppError::InvalidInput(
+                format!("PKH {} is not a participant of this wallet", actor_pkh)
+            ));
+        }
+        actor_pkh.into_inner()
+    };
+    
+    let mut tx = pool.write.begin_with("BEGIN IMMEDIATE").await?;
+    let changed = sqlx::query("UPDATE lock_participants SET active = ? WHERE lock_root_hash = ? AND pkh = ? AND active != ?")
+        .bind(req.active)
+        .bind(&lock_root_hash)
+        .bind(&pkh)
+        .bind(req.active)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected() > 0;
+    // Availability feeds reachability in the proposal detail, whose ETag follows updated_at
+    if changed {
+        sqlx::query(
+            "UPDATE proposals SET updated_at = ?1
+             WHERE status IN ('draft', 'pending')
+               AND (lock_root_hash = ?2 OR id IN (SELECT proposal_id FROM proposal_locks WHERE lock_root_hash = ?2))"
+        )
+        .bind(now_rfc3339())
+        .bind(&lock_root_hash)
+        .execute(&mut *tx)
+        .await?;
+    }
+    tx.commit().await?;
+    
+    tracing::info!(lock_root_hash = %lock_root_hash, pkh = %pkh, active = req.active, actor = %actor, "Participant availability updated");
+    
+    Ok(Json(ParticipantResponse {
+        lock_root_hash,
+        pkh,
+        active: req.active,
+    }))
+}
+
+#[utoipa::path(
+    get, path = "/{lock_root_hash}/allowed-recipients", tag = "multisigs",
+    params(("lock_root_hash" = String, Path, description = "Wallet lock-root hash")),
+    responses((status = 200, body = AllowedRecipientsResponse), (status = 404, body = ErrorBody))
+)]
+async fn list_allowed_recipients(
+    State(pool): State<DbPool>,
+    Path(lock_root_hash): Path<LockRootHash>,
+) -> Result<Json<AllowedRecipientsResponse>, AppError> {
+    ensure_lock_exists(&pool.read, &lock_root_hash).await?;
+    allowed_recipients_response(&pool.read, lock_root_hash).await
+}
+
+/// Adds a recipient to the wallet's allow-list; the first entry turns enforcement on
+#[utoipa::path(
+    post, path = "/{lock_root_hash}/allowed-recipients", tag = "multisigs",
+    params(("lock_root_hash" = String, Path, description = "Wallet lock-root hash")),
+    request_body = AddAllowedRecipientRequest,
+    security(("admin_token" = [])),
+    responses(
+        (status = 200, body = AllowedRecipientsResponse),
+        (status = 400, body = ErrorBody),
+        (status = 401, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    )
+)]
+async fn add_allowed_recipient(
+    _admin: AdminAuth,
+    State(pool): State<DbPool>,
+    Path(lock_root_hash): Path<LockRootHash>,
+    JsonBody(req): JsonBody<AddAllowedRecipientRequest>,
+) -> Result<Json<AllowedRecipientsResponse>, AppError> {
+    let recipient = req.recipient.trim();
+    if recipient.is_empty() {
+        return Err(AppError::InvalidInput("recipient cannot be empty".to_string()));
+    }
+    ensure_lock_exists(&pool.write, &lock_root_hash).await?;
+    
+    sqlx::query(
+        "INSERT INTO lock_allowed_recipients (lock_root_hash, recipient, added_at) VALUES (?, ?, ?)
+         ON CONFLICT (lock_root_hash, recipient) DO NOTHING"
+    )
+    .bind(&lock_root_hash)
+    .bind(recipient)
+    .bind(now_rfc3339())
+    .execute(&pool.write)
+    .await?;
+    
+    tracing::info!(lock_root_hash = %lock_root_hash, recipient = %recipient, "Allowed recipient added");
+    allowed_recipients_response(&pool.write, lock_root_hash).await
+}
+
+/// Removes a recipient; removing the last one lifts the restriction
+#[utoipa::path(
+    delete, path = "/{lock_root_hash}/allowed-recipients/{recipient}", tag = "multisigs",
+    params(
+        ("lock_root_hash" = String, Path, description = "Wallet lock-root hash"),
+        ("recipient" = String, Path, description = "Recipient to remove"),
+    ),
+    security(("admin_token" = [])),
+    responses(
+        (status = 200, body = AllowedRecipientsResponse),
+        (status = 401, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    )
+)]
+async fn remove_allowed_recipient(
+    _admin: AdminAuth,
+    State(pool): State<DbPool>,
+    Path((lock_root_hash, recipient)): Path<(LockRootHash, String)>,
+) -> Result<Json<AllowedRecipientsResponse>, AppError> {
+    let removed = sqlx::query(
+        "DELETE FROM lock_allowed_recipients WHERE lock_root_hash = ? AND recipient = ?"
+    )
+    .bind(&lock_root_hash)
+    .bind(&recipient)
+    .execute(&pool.write)
+    .await?
+    .rows_affected();
+    
+    if removed == 0 {
+        return Err(AppError::NotFound(
+            format!("Recipient {} is not on the allow-list of wallet {}", recipient, lock_root_hash)
+        ));
+    }
+    
+    tracing::info!(lock_root_hash = %lock_root_hash, recipient = %recipient, "Allowed recipient removed");
+    allowed_recipients_response(&pool.write, lock_root_hash).await
+}
+
+async fn ensure_lock_exists(pool: &Pool<Sqlite>, lock_root_hash: &LockRootHash) -> Result<(), AppError> {
+    let lock_exists: Option<i32> = sqlx::query_scalar(
+        "SELECT 1 FROM locks WHERE lock_root_hash = ? LIMIT 1"
+    )
+    .bind(lock_root_hash)
+    .fetch_optional(pool)
+    .await?;
+    
+    if lock_exists.is_none() {
+        return Err(AppError::NotFound(
+            format!("Wallet with lock_root_hash {} not found", lock_root_hash)
+        ));
+    }
+    Ok(())
+}
+
+async fn ensure_participant(pool: &Pool<Sqlite>, lock_root_hash: &LockRootHash, pkh: &Pkh) -> Result<(), AppError> {
+    let is_participant: Option<i32> = sqlx::query_scalar(
+        "SELECT 1 FROM lock_participants WHERE lock_root_hash = ? AND pkh = ?"
+    )
+    .bind(lock_root_hash)
+    .bind(pkh)
+    .fetch_optional(pool)
+    .await?;
+    
+    if is_participant.is_none() {
+        return Err(AppError::InvalidInput(
+            format!("PKH {} is not a participant of this wallet", pkh)
+        ));
+    }
+    Ok(())
+}
+
+async fn allowed_recipients_response(
+    pool: &Pool<Sqlite>,
+    lock_root_hash: LockRootHash,
+) -> Result<Json<AllowedRecipientsResponse>, AppError> {
+    let recipients: Vec<String> = sqlx::query_scalar(
+        "SELECT recipient FROM lock_allowed_recipients WHERE lock_root_hash = ? ORDER BY recipient"
+    )
+    .bind(&lock_root_hash)
+    .fetch_all(pool)
+    .await?;
+    
+    Ok(Json(AllowedRecipientsResponse { lock_root_hash, recipients }))
+}
+
+/// Delegations in this wallet, including revoked and expired ones, newest first
+#[utoipa::path(
+    get, path = "/{lock_root_hash}/delegations", tag = "multisigs",
+    params(("lock_root_hash" = String, Path, description = "Wallet lock-root hash")),
+    responses((status = 200, body = Vec<DelegationResponse>), (status = 404, body = ErrorBody))
+)]
+async fn list_delegations(
+    State(pool): State<DbPool>,
+    Path(lock_root_hash): Path<LockRootHash>,
+) -> Result<Json<Vec<DelegationResponse>>, AppError> {
+    ensure_lock_exists(&pool.read, &lock_root_hash).await?;
+    let delegations: Vec<Delegation> = sqlx::query_as::<_, Delegation>(
+        "SELECT * FROM delegations WHERE lock_root_hash = ? ORDER BY created_at DESC, id DESC"
+    )
+    .bind(&lock_root_hash)
+    .fetch_all(&pool.read)
+    .await?;
+    
+    Ok(Json(delegations.into_iter().map(DelegationResponse::from).collect()))
+}
+
+/// Lets `to_pkh` sign proposals on `from_pkh`'s behalf until `expires_at`, e.g. while
+/// `from_pkh` is on leave. Advisory accounting only: the submitted signed tx must still be valid
+#[utoipa::path(
+    post, path = "/{lock_root_hash}/delegations", tag = "multisigs",
+    params(("lock_root_hash" = String, Path, description = "Wallet lock-root hash")),
+    request_body = CreateDelegationRequest,
+    responses(
+        (status = 200, body = DelegationResponse),
+        (status = 400, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    )
+)]
+async fn create_delegation(
+    State(pool): State<DbPool>,
+    State(config): State<Arc<Config>>,
+    Path(lock_root_hash): Path<LockRootHash>,
+    JsonBody(req): JsonBody<CreateDelegationRequest>,
+) -> Result<Json<DelegationResponse>, AppError> {
+    if req.from_pkh == req.to_pkh {
+        return Err(AppError::InvalidInput("A participant cannot delegate to themselves".to_string()));
+    }
+    let now = now_rfc3339();
+    let expires_at = canonicalize_rfc3339(&req.expires_at)
+        .map_err(|e| AppError::InvalidInput(format!("Invalid expires_at: {}", e)))?;
+    if expires_at <= rfc3339_secs_from_now(config.max_clock_skew_secs) {
+        return Err(AppError::InvalidInput(format!(
+            "expires_at must be more than {}s in the future: {}", config.max_clock_skew_secs, req.expires_at
+        )));
+    }
+    
+    ensure_lock_exists(&pool.write, &lock_root_hash).await?;
+    ensure_participant(&pool.write, &lock_root_hash, &req.from_pkh).await?;
+    ensure_participant(&pool.write, &lock_root_hash, &req.to_pkh).await?;
+    
+    let delegation = Delegation {
+        id: Uuid::new_v4().to_string(),
+        lock_root_hash,
+        from_pkh: req.from_pkh,
+        to_pkh: req.to_pkh,
+        expires_at,
+        created_at: now,
+        revoked_at: None,
+    };
+    sqlx::query(
+        "INSERT INTO delegations (id, lock_root_hash, from_pkh, to_pkh, expires_at, created_at) VALUES (?, ?, ?, ?, ?, ?)"
+    )
+    .bind(&delegation.id)
+    .bind(&delegation.lock_root_hash)
+    .bind(&delegation.from_pkh)
+    .bind(&delegation.to_pkh)
+    .bind(&delegation.expires_at)
+    .bind(&delegation.created_at)
+    .execute(&pool.write)
+    .await?;
+    
+    tracing::info!(
+        lock_root_hash = %delegation.lock_root_hash,
+        from_pkh = %delegation.from_pkh,
+        to_pkh = %delegation.to_pkh,
+        expires_at = %delegation.expires_at,
+        "Delegation created"
+    );
+    Ok(Json(delegation.into()))
+}
+
+#[utoipa::path(
+    delete, path = "/{lock_root_hash}/delegations/{id}", tag = "multisigs",
+    params(
+        ("lock_root_hash" = String, Path, description = "Wallet lock-root hash"),
+        ("id" = String, Path, description = "Delegation ID"),
+    ),
+    request_body = RevokeDelegationRequest,
+    responses(
+        (status = 200, body = DelegationResponse),
+        (status = 400, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    )
+)]
+async fn revoke_delegation(
+    State(pool): State<DbPool>,
+    Path((lock_root_hash, id)): Path<(LockRootHash, String)>,
+    JsonBody(req): JsonBody<RevokeDelegationRequest>,
+) -> Result<Json<DelegationResponse>, AppError> {
+    let mut delegation: Delegation = sqlx::query_as::<_, Delegation>(
+        "SELECT * FROM delegations WHERE id = ? AND lock_root_hash = ?"
+    )
+    .bind(&id)
+    .bind(&lock_root_hash)
+    .fetch_optional(&pool.write)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Delegation {} not found in wallet {}", id, lock_root_hash)))?;
+    
+    if delegation.from_pkh != req.from_pkh {
+        return Err(AppError::InvalidInput("Only the delegating participant may revoke a delegation".to_string()));
+    }
+    if delegation.revoked_at.is_some() {
+        return Err(AppError::InvalidInput(format!("Delegation {} is already revoked", id)));
+    }
+    
+    let now = now_rfc3339();
+    sqlx::query("UPDATE delegations SET revoked_at = ? WHERE id = ?")
+        .bind(&now)
+        .bind(&id)
+        .execute(&pool.write)
+        .await?;
+    delegation.revoked_at = Some(now);
+    
+    tracing::info!(lock_root_hash = %lock_root_hash, delegation_id = %id, "Delegation revoked");
+    Ok(Json(delegation.into()))
+}
+
+/// Proposals scoped to one wallet; unlike `/api/proposals?lock_root_hash=`, unknown wallets are a 404
+#[utoipa::path(
+    get, path = "/{lock_root_hash}/proposals", tag = "multisigs",
+    params(
+        ("lock_root_hash" = String, Path, description = "Wallet lock-root hash"),
+        ListProposalsQuery,
+        ("If-Modified-Since" = Option<String>, Header, description = "Last-Modified from a previous response"),
+    ),
+    responses(
+        (status = 200, body = Vec<ProposalResponse>, headers(("Last-Modified" = String), ("X-Total-Count" = i64))),
+        (status = 304, description = "No proposal of this wallet changed since If-Modified-Since"),
+        (status = 400, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    )
+)]
+async fn list_wallet_proposals(
+    State(pool): State<DbPool>,
+    State(config): State<Arc<Config>>,
+    Path(lock_root_hash): Path<LockRootHash>,
+    FormQuery(params): FormQuery<ListProposalsQuery>,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
+    let lock_exists: Option<i32> = sqlx::query_scalar(
+        "SELECT 1 FROM locks WHERE lock_root_hash = ? LIMIT 1"
+    )
+    .bind(&lock_root_hash)
+    .fetch_optional(&pool.read)
+    .await?;
+    
+    if lock_exists.is_none() {
+        return Err(AppError::NotFound(
+            format!("Wallet with lock_root_hash {} not found", lock_root_hash)
+        ));
+    }
+    
+    // The path scopes the listing, so any pkh / lock_root_hash query filters are overridden
+    let params = ListProposalsQuery {
+        pkh: None,
+        lock_root_hash: Some(lock_root_hash),
+        ..params
+    };
+    proposals::list_proposals(State(pool), State(config), FormQuery(params), headers).await
+}
+
+/// Decommissioning cleanup: expires every pending or ready proposal spending from this wallet
+/// at once. Broadcast and confirmed proposals are untouched
+#[utoipa::path(
+    post, path = "/{lock_root_hash}/expire-open", tag = "multisigs",
+    params(("lock_root_hash" = String, Path, description = "Wallet lock-root hash")),
+    request_body = ExpireOpenRequest,
+    security((), ("admin_token" = [])),
+    responses(
+        (status = 200, body = ExpireOpenResponse),
+        (status = 400, body = ErrorBody),
+        (status = 401, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    )
+)]
+async fn expire_open_proposals(
+    admin: Option<AdminAuth>,
+    State(pool): State<DbPool>,
+    Path(lock_root_hash): Path<LockRootHash>,
+    JsonBody(req): JsonBody<ExpireOpenRequest>,
+) -> Result<Json<ExpireOpenResponse>, AppError> {
+    ensure_lock_exists(&pool.write, &lock_root_hash).await?;
+    
+    // Without an admin token, only a participant may expire the wallet's proposals
+    let actor = if admin.is_some() {
+        "admin".to_string()
+    } else {
+        let actor_pkh = req.actor_pkh.clone().ok_or_else(|| AppError::InvalidInput(
+            "actor_pkh is required without an admin token".to_string()
+        ))?;
+        ensure_participant(&pool.write, &lock_root_hash, &actor_pkh).await?;
+        actor_pkh.into_inner()
+    };
+    
+    const OPEN_PROPOSALS: &str = "status IN ('pending', 'ready')
+         AND (lock_root_hash = ? OR id IN (SELECT proposal_id FROM proposal_locks WHERE lock_root_hash = ?))";
+    let now = now_rfc3339();
+    let reason = req.reason.as_deref().map(str::trim).filter(|r| !r.is_empty());
+    let mut tx = pool.write.begin_with("BEGIN IMMEDIATE").await?;
+    let proposal_ids: Vec<String> = sqlx::query_scalar(&format!("SELECT id FROM proposals WHERE {}", OPEN_PROPOSALS))
+        .bind(&lock_root_hash)
+        .bind(&lock_root_hash)
+        .fetch_all(&mut *tx)
+        .await?;
+    for proposal_id in &proposal_ids {
+        sqlx::query(
+            "INSERT INTO proposal_events (id, proposal_id, event_type, actor, reason, created_at) VALUES (?, ?, 'expired', ?, ?, ?)"
+        )
+        .bind(Uuid::new_v4().to_string())
+        .bind(proposal_id)
+        .bind(&actor)
+        .bind(reason)
+        .bind(&now)
+        .execute(&mut *tx)
+        .await?;
+    }
+    
+    let expired = sqlx::query(&format!("UPDATE proposals SET status = ?, updated_at = ? WHERE {}", OPEN_PROPOSALS))
+        .bind(ProposalStatus::Expired.as_str())
+        .bind(&now)
+        .bind(&lock_root_hash)
+        .bind(&lock_root_hash)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+    tx.commit().await?;
+    
+    tracing::warn!(lock_root_hash = %lock_root_hash, actor = %actor, expired, "Expired open proposals for wallet");
+    Ok(Json(ExpireOpenResponse { lock_root_hash, expired }))
+}
+
+/// How often each participant signed, across open proposals and transaction history
+#[utoipa::path(
+    get, path = "/{lock_root_hash}/signer-stats", tag = "multisigs",
+    params(("lock_root_hash" = String, Path, description = "Wallet lock-root hash")),
+    responses((status = 200, body = SignerStatsResponse), (status = 404, body = ErrorBody))
+)]
+async fn signer_stats(
+    State(pool): State<DbPool>,
+    Path(lock_root_hash): Path<LockRootHash>,
+) -> Result<Json<SignerStatsResponse>, AppError> {
+    let lock_exists: Option<i32> = sqlx::query_scalar(
+        "SELECT 1 FROM locks WHERE lock_root_hash = ? LIMIT 1"
+    )
+    .bind(&lock_root_hash)
+    .fetch_optional(&pool.read)
+    .await?;
+    
+    if lock_exists.is_none() {
+        return Err(AppError::NotFound(
+            format!("Wallet with lock_root_hash {} not found", lock_root_hash)
+        ));
+    }
+    
+    // Broadcast proposals keep their signature rows but are also recorded in history (possibly
+    // under a different final tx_id), so their signatures are counted from history only.
+    // Drafts haven't been shown to co-signers yet and don't count
+    let rows: Vec<SignerStatRow> = sqlx::query_as::<_, SignerStatRow>(
+        "WITH signed AS (
+             SELECT s.signer_pkh AS pkh, s.signed_at
+             FROM proposal_signatures s
+             INNER JOIN proposals p ON p.id = s.proposal_id
+             WHERE p.lock_root_hash = ? AND p.status NOT IN ('draft', 'broadcast', 'confirmed')
+             UNION ALL
+             SELECT j.value AS pkh, COALESCE(h.broadcast_at, h.created_at) AS signed_at
+             FROM transaction_history h, json_each(h.signers_json) j
+             WHERE h.lock_root_hash = ?
+         )
+         SELECT lp.pkh, COUNT(t.pkh) AS signatures, MAX(t.signed_at) AS last_signed_at
+         FROM lock_participants lp
+         LEFT JOIN signed t ON t.pkh = lp.pkh
+         WHERE lp.lock_root_hash = ?
+         GROUP BY lp.pkh
+         ORDER BY signatures DESC, last_signed_at DESC, lp.pkh"
+    )
+    .bind(&lock_root_hash)
+    .bind(&lock_root_hash)
+    .bind(&lock_root_hash)
+    .fetch_all(&pool.read)
+    .await?;
+    
+    let signers = rows.into_iter().map(|row| SignerStat {
+        pkh: row.pkh,
+        signatures: row.signatures,
+        last_signed_at: row.last_signed_at,
+    }).collect();
+    
+    Ok(Json(SignerStatsResponse { lock_root_hash, signers }))
+}
+
+/// Distinct recipients this wallet has paid, from the declared seeds of its transaction history
+#[utoipa::path(
+    get, path = "/{lock_root_hash}/recipients", tag = "multisigs",
+    params(("lock_root_hash" = String, Path, description = "Wallet lock-root hash")),
+    responses((status = 200, body = PaidRecipientsResponse), (status = 404, body = ErrorBody))
+)]
+async fn list_paid_recipients(
+    State(pool): State<DbPool>,
+    State(config): State<Arc<Config>>,
+    Path(lock_root_hash): Path<LockRootHash>,
+) -> Result<Json<PaidRecipientsResponse>, AppError> {
+    ensure_lock_exists(&pool.read, &lock_root_hash).await?;
+    
+    // Failed transactions never paid anyone. A row whose seeds_json is not valid JSON is skipped
+    // rather than failing the whole query, and a recipient seeded twice in one transaction
+    // counts as one payment
+    let rows: Vec<RecipientTotalRow> = sqlx::query_as::<_, RecipientTotalRow>(
+        "WITH history AS (
+             SELECT id, seeds_json, COALESCE(broadcast_at, created_at) AS paid_at
+             FROM transaction_history WHERE lock_root_hash = ? AND status != 'failed'
+             UNION ALL
+             SELECT id, seeds_json, COALESCE(broadcast_at, created_at) AS paid_at
+             FROM archived_history WHERE lock_root_hash = ? AND status != 'failed'
+         )
+         SELECT json_extract(s.value, '$.recipient') AS recipient,
+                COUNT(DISTINCT h.id) AS payments,
+                COALESCE(SUM(json_extract(s.value, '$.amount_nicks')), 0) AS total_paid_nicks,
+                MAX(h.paid_at) AS last_paid_at
+         FROM history h,
+              json_each(CASE WHEN json_valid(h.seeds_json) THEN h.seeds_json ELSE '[]' END) s
+         WHERE json_extract(s.value, '$.recipient') IS NOT NULL
+         GROUP BY recipient
+         ORDER BY last_paid_at DESC, recipient"
+    )
+    .bind(&lock_root_hash)
+    .bind(&lock_root_hash)
+    .fetch_all(&pool.read)
+    .await?;
+    
+    let recipients = rows.into_iter().map(|row| PaidRecipient {
+        total_paid_display: config.format_nicks(row.total_paid_nicks),
+        recipient: row.recipient,
+        payments: row.payments,
+        total_paid_nicks: row.total_paid_nicks,
+        last_paid_at: row.last_paid_at,
+    }).collect();
+    
+    Ok(Json(PaidRecipientsResponse { lock_root_hash, recipients }))
+}
+
+fn proposal_velocity(last_24h: i64, baseline: i64, config: &Config) -> ProposalVelocity {
+    let daily_average = baseline as f64 / f64::from(config.anomaly_baseline_days);
+    ProposalVelocity {
+        last_24h,
+        daily_average,
+        anomalous: last_24h as f64 > config.anomaly_velocity_multiple * daily_average.max(1.0),
+    }
+}
+
+/// Other wallets with at least one participant in common with this one
+#[utoipa::path(
+    get, path = "/{lock_root_hash}/related", tag = "multisigs",
+    params(("lock_root_hash" = String, Path, description = "Wallet lock-root hash")),
+    responses((status = 200, body = RelatedWalletsResponse), (status = 404, body = ErrorBody))
+)]
+async fn list_related_wallets(
+    State(pool): State<DbPool>,
+    Path(lock_root_hash): Path<LockRootHash>,
+) -> Result<Json<RelatedWalletsResponse>, AppError> {
+    ensure_lock_exists(&pool.read, &lock_root_hash).await?;
+    
+    // One row per (other wallet, shared participant), grouped below
+    let rows: Vec<SharedParticipantRow> = sqlx::query_as::<_, SharedParticipantRow>(
+        "SELECT l.lock_root_hash, l.threshold, l.total_signers, l.created_at, l.metadata_json, other.pkh
+         FROM lock_participants mine
+         INNER JOIN lock_participants other
+             ON other.pkh = mine.pkh AND other.lock_root_hash != mine.lock_root_hash
+         INNER JOIN locks l ON l.lock_root_hash = other.lock_root_hash
+         WHERE mine.lock_root_hash = ?
+         ORDER BY l.lock_root_hash, other.pkh"
+    )
+    .bind(&lock_root_hash)
+    .fetch_all(&pool.read)
+    .await?;
+    
+    let mut wallets: Vec<RelatedWallet> = Vec::new();
+    for row in rows {
+        match wallets.last_mut() {
+            Some(wallet) if wallet.lock_root_hash == row.lock_root_hash => wallet.shared_participants.push(row.pkh),
+            _ => wallets.push(RelatedWallet {
+                lock_root_hash: row.lock_root_hash,
+                threshold: row.threshold,
+                total_signers: row.total_signers,
+                created_at: row.created_at,
+                metadata_json: row.metadata_json,
+                shared_participants: vec![row.pkh],
+            }),
+        }
+    }
+    // Stable, so ties keep lock_root_hash order
+    wallets.sort_by_key(|wallet| std::cmp::Reverse(wallet.shared_participants.len()));
+    
+    Ok(Json(RelatedWalletsResponse { lock_root_hash, wallets }))
+}
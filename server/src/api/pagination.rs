@@ -0,0 +1,49 @@
+use crate::config::Config;
+use crate::error::AppError;
+use sqlx::FromRow;
+
+/// Size of the whole filtered set on paginated list responses
+pub const TOTAL_COUNT_HEADER: &str = "x-total-count";
+
+/// LIMIT/OFFSET for list endpoints, bounded by DEFAULT_PAGE_SIZE / MAX_PAGE_SIZE
+#[derive(Debug, Clone, Copy)]
+pub struct Pagination {
+    pub limit: i64,
+    pub offset: i64,
+}
+
+impl Pagination {
+    /// A missing limit uses the configured default and one above the max is clamped;
+    /// non-positive limits and negative offsets are rejected
+    pub fn from_query(limit: Option<i64>, offset: Option<i64>, config: &Config) -> Result<Self, AppError> {
+        let limit = match limit {
+            None => config.default_page_size,
+            Some(limit) if limit <= 0 => {
+                return Err(AppError::InvalidInput(format!("limit must be positive: {}", limit)));
+            }
+            Some(limit) => limit.min(config.max_page_size),
+        };
+        let offset = offset.unwrap_or(0);
+        if offset < 0 {
+            return Err(AppError::InvalidInput(format!("offset must not be negative: {}", offset)));
+        }
+        Ok(Self { limit, offset })
+    }
+}
+
+/// A page row carrying the size of the whole filtered set, selected alongside the row as
+/// `COUNT(*) OVER () AS total_count` so one query yields both the page and the total
+#[derive(Debug, FromRow)]
+pub struct Counted<T> {
+    #[sqlx(flatten)]
+    pub row: T,
+    pub total_count: i64,
+}
+
+impl<T> Counted<T> {
+    /// The rows and the total; `None` when the page is empty, since no row carried the count
+    pub fn split(rows: Vec<Self>) -> (Vec<T>, Option<i64>) {
+        let total = rows.first().map(|first| first.total_count);
+        (rows.into_iter().map(|counted| counted.row).collect(), total)
+    }
+}
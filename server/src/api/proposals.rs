@@ -1,20 +1,36 @@
 use axum::{
     extract::{Path, Query, State},
-    routing::{get, post},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    routing::{delete, get, post},
     Json, Router,
 };
+use axum_extra::extract::Query as FormQuery; // Accepts repeated keys (signed_by=A&signed_by=B)
+use futures_util::StreamExt;
 use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, OpenApi, ToSchema};
+use std::sync::Arc;
+use sqlx::{SqliteConnection, SqliteExecutor};
 use uuid::Uuid;
-use crate::db::{DbPool, Proposal, ProposalSignature, TransactionHistory, ProposalStatus, TransactionStatus};
-use crate::error::AppError;
+use crate::config::Config;
+use crate::events::{EventSink, ProposalEvent};
+use crate::api::AppState;
+use crate::api::json_body::JsonBody;
+use crate::api::admin::AdminAuth;
+use crate::api::pagination::{Counted, Pagination, TOTAL_COUNT_HEADER};
+use crate::api::raw_tx_links;
+use crate::api::streaming::json_array_body;
+use crate::db::{format_rfc3339, now_rfc3339, rfc3339_secs_from_now, canonicalize_rfc3339, parse_relative_duration, DbPool, LockRootHash, Pkh, Proposal, ProposalComment, ProposalSignature, TransactionHistory, ProposalStatus, ProposalType, TransactionStatus};
+use crate::error::{AppError, ErrorBody};
+use crate::validation::is_base58;
 
 // === Request/Response types ===
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct CreateProposalRequest {
     pub tx_id: String,
-    pub lock_root_hash: String,
-    pub proposer_pkh: String,
+    pub lock_root_hash: LockRootHash,
+    pub proposer_pkh: Pkh,
     pub threshold: i32,
     pub raw_tx_json: String,           // Serialized unsigned RawTx
     pub notes_json: String,            // Serialized notes for signing
@@ -22,147 +38,555 @@ pub struct CreateProposalRequest {
     pub total_input_nicks: i64,
     pub seeds: Vec<SeedSummary>,       // Human-readable seed info
     pub proposer_signed_tx_json: String, // Proposer signs at creation
+    /// Further wallets whose inputs this proposal spends; each must reach its own threshold
+    #[serde(default)]
+    pub additional_lock_root_hashes: Vec<LockRootHash>,
+    pub expires_at: Option<String>, // RFC 3339; signing is refused afterwards
+    #[serde(default)]
+    pub proposal_type: ProposalType, // Defaults to other
+    /// Save without notifying co-signers; only the proposer sees it until `POST /{id}/publish`
+    #[serde(default)]
+    pub draft: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+/// Declared by the proposer for display; not checked against the outputs in `raw_tx_json`
+#[derive(Debug, Serialize, Deserialize, Clone, ToSchema)]
 pub struct SeedSummary {
     pub recipient: String,
     pub amount_nicks: i64,
 }
 
-#[derive(Debug, Serialize)]
+/// A seed as returned to clients, with the amount also rendered in the configured denomination
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SeedResponse {
+    pub recipient: String,
+    pub amount_nicks: i64,
+    pub amount_display: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
 pub struct CreateProposalResponse {
     pub id: String,
     pub tx_id: String,
 }
 
-#[derive(Debug, Deserialize)]
+/// Outcome of `insert_proposal`
+#[derive(Debug)]
+pub(crate) struct CreatedProposal {
+    pub id: String,
+    pub tx_id: String,
+    pub ready: bool, // Proposer's signature alone met the threshold
+    pub signatures_collected: i32,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct ListProposalsQuery {
-    pub pkh: Option<String>,           // Filter by participant PKH
-    pub lock_root_hash: Option<String>, // Filter by wallet
+    pub pkh: Option<Pkh>,           // Filter by participant PKH
+    pub lock_root_hash: Option<LockRootHash>, // Filter by wallet
     pub status: Option<String>,        // Filter by status
+    #[serde(rename = "type")]
+    #[param(rename = "type")]
+    pub proposal_type: Option<String>, // Filter by proposal type
+    pub include_signers: Option<bool>, // Default true; false returns only signatures_collected
+    pub since: Option<String>,         // Only proposals created within this window, e.g. 24h, 7d
+    /// Repeatable: only proposals every one of these PKHs has signed
+    #[serde(default)]
+    pub signed_by: Vec<Pkh>,
+    /// Repeatable: only proposals none of these PKHs has signed
+    #[serde(default)]
+    pub not_signed_by: Vec<Pkh>,
+    pub limit: Option<i64>,            // Defaults to DEFAULT_PAGE_SIZE, clamped to MAX_PAGE_SIZE
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct DiscardDraftsQuery {
+    pub proposer_pkh: Pkh, // Only this proposer's drafts are removed
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ProposalViewQuery {
+    pub viewer_pkh: Option<Pkh>, // Adds viewer_has_signed / viewer_can_sign for this PKH; the proposer's PKH also unlocks drafts
+    /// Default true; pass false for large wallets and page through `/{id}/signatures` instead
+    pub include_signatures: Option<bool>,
+}
+
+/// A signed link minted by `POST /{id}/raw-tx-link`; needed when RAW_TX_LINK_SECRET is set
+/// and the caller has no admin token
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct RawTxLinkQuery {
+    pub expires: Option<i64>, // Unix seconds
+    pub sig: Option<String>,  // Hex HMAC-SHA256
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SignaturesQuery {
+    pub cursor: Option<String>, // next_cursor from the previous page
+    pub limit: Option<i64>,
+    pub viewer_pkh: Option<Pkh>, // The proposer's PKH, to read a draft
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ViewerQuery {
+    pub viewer_pkh: Option<Pkh>, // The proposer's PKH, to read a draft
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ReadyProposalsQuery {
+    pub pkh: Option<Pkh>,            // Filter by participant PKH
+    pub lock_root_hash: Option<LockRootHash>, // Filter by wallet
+    pub limit: Option<i64>,             // Defaults to DEFAULT_PAGE_SIZE, clamped to MAX_PAGE_SIZE
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ExpiringProposalsQuery {
+    pub within: Option<String>,         // Required lookahead window, e.g. 6h or 2d (at most MAX_EXPIRING_WINDOW_DAYS)
+    pub pkh: Option<Pkh>,            // Filter by participant PKH
+    pub lock_root_hash: Option<LockRootHash>, // Filter by wallet
+    pub limit: Option<i64>,             // Defaults to DEFAULT_PAGE_SIZE, clamped to MAX_PAGE_SIZE
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct HistoryQuery {
+    pub pkh: Option<Pkh>,            // Filter by participant PKH
+    pub lock_root_hash: Option<LockRootHash>, // Filter by wallet
+    pub status: Option<String>,         // Filter by status: broadcast, confirmed or failed
+    pub sort: Option<String>,           // "broadcast_at" (default) or "confirmed_at", newest first
+    pub limit: Option<i64>,             // Defaults to DEFAULT_PAGE_SIZE, clamped to MAX_PAGE_SIZE
+    pub offset: Option<i64>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ProposalResponse {
     pub id: String,
     pub tx_id: String,
-    pub lock_root_hash: String,
-    pub proposer_pkh: String,
-    pub status: String,
+    pub lock_root_hash: LockRootHash,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub additional_lock_root_hashes: Vec<LockRootHash>, // Omitted for single-wallet proposals
+    pub proposer_pkh: Pkh,
+    pub proposal_type: ProposalType,
+    pub status: ProposalStatus,
     pub threshold: i32,
     pub signatures_collected: i32,
     pub total_input_nicks: i64,
-    pub seeds: Vec<SeedSummary>,
-    pub signers: Vec<String>,          // PKHs who have signed
+    pub total_input_display: String,
+    pub seeds: Vec<SeedResponse>,
+    pub seeds_corrupt: bool,           // Stored seeds failed to parse; `seeds` is empty, not "no recipients"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signers: Option<Vec<Pkh>>,  // PKHs who have signed (omitted when include_signers=false)
     pub created_at: String,
     pub updated_at: String,
+    pub expires_at: Option<String>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct SignatureEntry {
-    pub signer_pkh: String,
-    pub signed_tx_json: String,
+    pub signer_pkh: Pkh,
+    /// Omitted outside /raw-tx when RAW_TX_LINK_SECRET is set and no admin token was sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signed_tx_json: Option<String>,
     pub signed_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delegate_pkh: Option<Pkh>, // Who actually signed, when done on signer_pkh's behalf
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signer_note: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SignaturePageResponse {
+    pub signatures: Vec<SignatureEntry>, // Ordered by signing time
+    pub next_cursor: Option<String>,
+}
+
+/// Just what a broadcaster needs to assemble the final transaction
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RawTxResponse {
+    pub raw_tx_json: String,
+    pub signatures: Vec<SignatureEntry>, // Ordered by signing time
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RawTxLinkResponse {
+    pub url: String, // Path and query of the raw-tx request, relative to this server
+    pub expires_at: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
 pub struct ProposalDetailResponse {
     pub id: String,
     pub tx_id: String,
-    pub lock_root_hash: String,
-    pub proposer_pkh: String,
-    pub status: String,
-    pub threshold: i32,
+    pub lock_root_hash: LockRootHash,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub additional_lock_root_hashes: Vec<LockRootHash>, // Omitted for single-wallet proposals
+    pub proposer_pkh: Pkh,
+    pub proposal_type: ProposalType,
+    pub status: ProposalStatus,
+    pub threshold: i32, // Required from the primary wallet; additional wallets use their own
     pub signatures_collected: i32,
-    pub raw_tx_json: String,
+    /// Omitted when RAW_TX_LINK_SECRET is set and no admin token was sent; use a signed raw-tx link
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_tx_json: Option<String>,
     pub notes_json: String,
     pub spend_conditions_json: String,
     pub total_input_nicks: i64,
-    pub seeds: Vec<SeedSummary>,
-    pub signers: Vec<String>,
-    /// All collected signatures with their signed tx data
-    pub signatures: Vec<SignatureEntry>,
-    pub participants: Vec<String>,     // All wallet participants
+    pub total_input_display: String,
+    pub seeds: Vec<SeedResponse>,
+    pub seeds_corrupt: bool,           // Stored seeds failed to parse; `seeds` is empty, not "no recipients"
+    pub signers: Vec<Pkh>,
+    /// All collected signatures with their signed tx data (omitted when include_signatures=false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signatures: Option<Vec<SignatureEntry>>,
+    pub participants: Vec<Pkh>,     // All participants of every wallet spent from
+    pub inactive_participants: Vec<Pkh>, // Participants marked unavailable
+    pub unmet_required_signers: Vec<Pkh>, // Required signers who haven't signed; readiness waits for them
+    /// Signatures collected plus those still obtainable from active participants;
+    /// rejections are not tracked yet
+    pub max_possible_signatures: i32,
+    pub threshold_reachable: bool,     // max_possible_signatures >= threshold
     pub created_at: String,
     pub updated_at: String,
+    pub expires_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub viewer_has_signed: Option<bool>, // Only with viewer_pkh
+    /// Only with viewer_pkh: participant, proposal pending and unexpired, not yet signed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub viewer_can_sign: Option<bool>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ProposalStatusResponse {
+    pub status: ProposalStatus,
+    pub signatures_collected: i32,
+    pub threshold: i32,
+    pub ready_to_broadcast: bool,
+    pub max_possible_signatures: i32,
+    pub threshold_reachable: bool,
+}
+
+/// Status shape plus enough identity for a broadcaster to pick the proposal up
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReadyProposalResponse {
+    pub id: String,
+    pub tx_id: String,
+    pub lock_root_hash: LockRootHash,
+    pub created_at: String,
+    #[serde(flatten)]
+    pub status: ProposalStatusResponse,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct SignProposalRequest {
-    pub signer_pkh: String,
+    pub signer_pkh: Pkh,
     /// The signed RawTx protobuf as JSON - contains this signer's signature
     pub signed_tx_json: String,
+    /// Must be greater than the last nonce accepted for this signer on this proposal
+    pub nonce: i64,
+    /// Sign as a delegate of this participant; requires an active delegation to signer_pkh
+    #[serde(default)]
+    pub on_behalf_of: Option<Pkh>,
+    /// Provenance of a signature collected out of band, e.g. "Ledger #2 (CFO)"; informational
+    /// only, at most 256 bytes
+    #[serde(default)]
+    pub signer_note: Option<String>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct SignProposalResponse {
     pub success: bool,
     pub signatures_collected: i32,
     pub ready_to_broadcast: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_behalf_of: Option<Pkh>, // Set when the signature was counted via delegation
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ValidateSignatureRequest {
+    pub signer_pkh: Pkh,
+    pub signed_tx_json: String,
+    #[serde(default)]
+    pub on_behalf_of: Option<Pkh>,
+}
+
+/// Broadcast gate over the whole signature set
+#[derive(Debug, Serialize, ToSchema)]
+pub struct VerifyProposalResponse {
+    pub valid: bool, // open, threshold_met and no invalid signers: safe to broadcast now
+    pub open: bool, // Pending or ready and not past expires_at; false once broadcast, confirmed or expired
+    pub threshold_met: bool, // Counting only signatures that pass verification, plus the wallet policies (required signers, per-wallet thresholds)
+    pub invalid_signers: Vec<Pkh>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ValidateSignatureResponse {
+    pub valid: bool,
+    pub reason: Option<String>, // Why signing would be rejected
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateProposalRequest {
+    pub proposer_pkh: Pkh, // Only the proposer may edit
+    pub threshold: Option<i32>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UpdateProposalResponse {
+    pub id: String,
+    pub threshold: i32,
+    pub signatures_collected: i32,
+    pub ready_to_broadcast: bool,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct WithdrawSignatureRequest {
+    pub signer_pkh: Pkh,
+    pub reason: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WithdrawSignatureResponse {
+    pub success: bool,
+    pub signatures_collected: i32,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ForceReadyRequest {
+    pub actor: String,  // Operator performing the override, recorded in the audit trail
+    pub reason: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct PublishProposalRequest {
+    pub proposer_pkh: Pkh, // Only the proposer may publish
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DiscardDraftsResponse {
+    pub deleted: u64,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ReassignProposerRequest {
+    pub proposer_pkh: Pkh, // Must participate in a wallet the proposal spends from
+    pub actor: String,        // Operator performing the change, recorded in the audit trail
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReassignProposerResponse {
+    pub id: String,
+    pub previous_proposer_pkh: Pkh,
+    pub proposer_pkh: Pkh,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AddCommentRequest {
+    pub author_pkh: Pkh,
+    pub body: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CommentResponse {
+    pub id: String,
+    pub author_pkh: Pkh,
+    pub body: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct BroadcastProposalRequest {
-    pub _broadcaster_pkh: String,
+    pub _broadcaster_pkh: Pkh,
     /// The final transaction ID after merging signatures (may differ from original proposal tx_id)
     pub final_tx_id: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct DirectSpendRequest {
     pub tx_id: String,
-    pub lock_root_hash: String,
-    pub sender_pkh: String,
+    pub lock_root_hash: LockRootHash,
+    pub sender_pkh: Pkh,
     pub total_input_nicks: i64,
     pub seeds: Vec<SeedSummary>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct DirectSpendResponse {
     pub success: bool,
     pub history_id: String,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct TransactionHistoryResponse {
     pub id: String,
     pub tx_id: String,
-    pub lock_root_hash: String,
-    pub proposer_pkh: String,
-    pub status: String,
+    pub lock_root_hash: LockRootHash,
+    pub proposer_pkh: Pkh,
+    pub status: TransactionStatus,
     pub total_input_nicks: i64,
-    pub seeds: Vec<SeedSummary>,
-    pub signers: Vec<String>,
+    pub total_input_display: String,
+    pub seeds: Vec<SeedResponse>,
+    pub seeds_corrupt: bool,   // Stored seeds failed to parse; `seeds` is empty, not "no recipients"
+    pub signers: Vec<Pkh>,
+    pub signers_corrupt: bool, // Stored signers failed to parse; `signers` is empty
     pub created_at: String,
     pub broadcast_at: Option<String>,
     pub confirmed_at: Option<String>,
+    pub block_height: Option<i64>,
+    pub confirmations: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RebroadcastResponse {
+    pub history_id: String,
+    pub tx_id: String,
+    pub broadcast_at: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ConfirmTransactionEntry {
+    pub tx_id: String,
+    pub confirmed_at: String, // RFC3339
+    pub block_height: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ConfirmBatchResponse {
+    pub updated: Vec<String>,   // tx_ids whose history rows were marked confirmed
+    pub not_found: Vec<String>, // tx_ids with no matching history row
+    pub not_updated: Vec<String>, // tx_ids whose history row isn't broadcast (already confirmed, or failed)
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct ChainTipRequest {
+    pub block_height: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ChainTipResponse {
+    pub updated: u64, // Confirmed history rows whose confirmation depth was refreshed
 }
 
+// === OpenAPI ===
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(create_proposal, list_proposals, get_proposal, update_proposal, publish_proposal, discard_drafts, get_proposal_by_tx, get_proposal_status, get_raw_tx, create_raw_tx_link, list_signatures, verify_proposal, list_ready_proposals, list_expiring_proposals, validate_signature, sign_proposal, withdraw_signature, force_ready, reassign_proposer, list_comments, add_comment, mark_broadcast, get_history, rebroadcast_history, confirm_history_batch, update_chain_tip, direct_spend),
+    components(schemas(ErrorBody))
+)]
+pub struct ProposalsApi;
+
 // === Router ===
 
-pub fn router() -> Router<DbPool> {
+pub fn router() -> Router<AppState> {
     Router::new()
         .route("/", post(create_proposal).get(list_proposals))
-        .route("/:id", get(get_proposal))
-        .route("/:id/sign", post(sign_proposal))
+        .route("/ready", get(list_ready_proposals))
+        .route("/expiring", get(list_expiring_proposals))
+        .route("/drafts", delete(discard_drafts))
+        .route("/:id", get(get_proposal).patch(update_proposal))
+        .route("/by-tx/:tx_id", get(get_proposal_by_tx))
+        .route("/:id/status", get(get_proposal_status))
+        .route("/:id/raw-tx", get(get_raw_tx))
+        .route("/:id/raw-tx-link", post(create_raw_tx_link))
+        .route("/:id/signatures", get(list_signatures))
+        .route("/:id/verify", get(verify_proposal))
+        .route("/:id/sign", post(sign_proposal).delete(withdraw_signature))
+        .route("/:id/sign/validate", post(validate_signature))
+        .route("/:id/publish", post(publish_proposal))
+        .route("/:id/force-ready", post(force_ready))
+        .route("/:id/reassign-proposer", post(reassign_proposer))
+        .route("/:id/comments", get(list_comments).post(add_comment))
         .route("/:id/broadcast", post(mark_broadcast))
         .route("/history", get(get_history))
+        .route("/history/confirm-batch", post(confirm_history_batch))
+        .route("/history/:id/rebroadcast", post(rebroadcast_history))
+        .route("/history/chain-tip", post(update_chain_tip))
         .route("/direct", post(direct_spend))
 }
 
 // === Handlers ===
 
+#[utoipa::path(
+    post, path = "", tag = "proposals",
+    request_body = CreateProposalRequest,
+    responses(
+        (status = 200, body = CreateProposalResponse),
+        (status = 400, body = ErrorBody),
+        (status = 404, description = "Wallet not found", body = ErrorBody),
+    )
+)]
 async fn create_proposal(
     State(pool): State<DbPool>,
-    Json(req): Json<CreateProposalRequest>,
+    State(config): State<Arc<Config>>,
+    State(events): State<Arc<dyn EventSink>>,
+    JsonBody(req): JsonBody<CreateProposalRequest>,
 ) -> Result<Json<CreateProposalResponse>, AppError> {
+    let mut tx = pool.write.begin_with("BEGIN IMMEDIATE").await?;
+    let created = insert_proposal(&mut tx, &config, &req).await?;
+    tx.commit().await?;
+    emit_proposal_created(events.as_ref(), &req, &created);
+    
+    Ok(Json(CreateProposalResponse {
+        id: created.id,
+        tx_id: created.tx_id,
+    }))
+}
+
+/// Fixed namespace for deterministic proposal ids; changing it changes every derived id
+const PROPOSAL_ID_NAMESPACE: Uuid = Uuid::from_u128(0x6460df29_6e86_43a8_a249_90ec6c94ebac);
+
+/// Random by default. With DETERMINISTIC_PROPOSAL_IDS, a UUIDv5 over the wallet and tx_id, so
+/// independent instances give the same proposal the same id
+fn new_proposal_id(config: &Config, lock_root_hash: &LockRootHash, tx_id: &str) -> Uuid {
+    if config.deterministic_proposal_ids {
+        Uuid::new_v5(&PROPOSAL_ID_NAMESPACE, format!("{}:{}", lock_root_hash, tx_id).as_bytes())
+    } else {
+        Uuid::new_v4()
+    }
+}
+
+/// Validates a proposal and inserts it with the proposer's signature, marking it ready if that
+/// already meets the threshold (drafts stay drafts). Events are left to the caller, to send once
+/// the data is committed.
+pub(crate) async fn insert_proposal(
+    conn: &mut SqliteConnection,
+    config: &Config,
+    req: &CreateProposalRequest,
+) -> Result<CreatedProposal, AppError> {
+    validate_json_field("notes_json", &req.notes_json, config.max_notes_json_bytes)?;
+    validate_json_field("spend_conditions_json", &req.spend_conditions_json, config.max_spend_conditions_json_bytes)?;
+    validate_fee_cap(req)?;
+    check_allowed_recipients(&mut *conn, &req.lock_root_hash, &req.seeds).await?;
+    let expires_at = req.expires_at.as_deref()
+        .map(|value| {
+            let canonical = canonicalize_rfc3339(value)
+                .map_err(|e| AppError::InvalidInput(format!("Invalid expires_at: {}", e)))?;
+            // Anything inside the skew window may already have passed by the client's clock
+            if canonical <= rfc3339_secs_from_now(config.max_clock_skew_secs) {
+                return Err(AppError::InvalidInput(format!(
+                    "expires_at must be more than {}s in the future: {}", config.max_clock_skew_secs, value
+                )));
+            }
+            Ok(canonical)
+        })
+        .transpose()?;
+    
     // Check if proposal with this tx_id already exists
     let existing: Option<String> = sqlx::query_scalar(
         "SELECT id FROM proposals WHERE tx_id = ? LIMIT 1"
     )
     .bind(&req.tx_id)
-    .fetch_optional(&pool)
+    .fetch_optional(&mut *conn)
     .await?;
     
     if let Some(existing_id) = existing {
@@ -176,7 +600,7 @@ async fn create_proposal(
         "SELECT 1 FROM locks WHERE lock_root_hash = ? LIMIT 1"
     )
     .bind(&req.lock_root_hash)
-    .fetch_optional(&pool)
+    .fetch_optional(&mut *conn)
     .await?;
     
     if lock_exists.is_none() {
@@ -185,27 +609,56 @@ async fn create_proposal(
         ));
     }
     
-    let proposal_id = Uuid::new_v4().to_string();
-    let now = chrono::Utc::now().to_rfc3339();
+    // Multi-wallet spends: every additional wallet must exist and permit the recipients
+    for (i, hash) in req.additional_lock_root_hashes.iter().enumerate() {
+        if *hash == req.lock_root_hash || req.additional_lock_root_hashes[..i].contains(hash) {
+            return Err(AppError::InvalidInput(
+                format!("Duplicate lock_root_hash in additional_lock_root_hashes: {}", hash)
+            ));
+        }
+        let exists: Option<i32> = sqlx::query_scalar(
+            "SELECT 1 FROM locks WHERE lock_root_hash = ? LIMIT 1"
+        )
+        .bind(hash)
+        .fetch_optional(&mut *conn)
+        .await?;
+        if exists.is_none() {
+            return Err(AppError::NotFound(
+                format!("Wallet with lock_root_hash {} not found", hash)
+            ));
+        }
+        check_allowed_recipients(&mut *conn, hash, &req.seeds).await?;
+    }
+    
+    let proposal_id = new_proposal_id(config, &req.lock_root_hash, &req.tx_id).to_string();
+    let created = chrono::Utc::now();
+    let now = format_rfc3339(created);
+    
+    // The strictest signing window among the wallets spent from caps the deadline
+    let mut window_secs: Option<i64> = None;
+    for hash in std::iter::once(&req.lock_root_hash).chain(&req.additional_lock_root_hashes) {
+        let secs: Option<i64> = sqlx::query_scalar("SELECT signing_window_secs FROM locks WHERE lock_root_hash = ?")
+            .bind(hash)
+            .fetch_one(&mut *conn)
+            .await?;
+        window_secs = window_secs.into_iter().chain(secs).min();
+    }
+    let window_deadline = window_secs.map(|secs| format_rfc3339(created + chrono::Duration::seconds(secs)));
+    let expires_at = expires_at.into_iter().chain(window_deadline).min();
     let seeds_json = serde_json::to_string(&req.seeds)
         .map_err(|e| AppError::InvalidInput(format!("Failed to serialize seeds: {}", e)))?;
     
-    let status_str = serde_json::to_string(&ProposalStatus::Pending)
-        .map_err(|e| AppError::InvalidInput(format!("Failed to serialize status: {}", e)))?
-        .trim_matches('"')
-        .to_string();
-    
     sqlx::query(
         "INSERT INTO proposals (id, tx_id, lock_root_hash, proposer_pkh, status, threshold, 
          raw_tx_json, notes_json, spend_conditions_json, total_input_nicks, seeds_json, 
-         created_at, updated_at) 
-         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+         created_at, updated_at, expires_at, proposal_type) 
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
     )
     .bind(&proposal_id)
     .bind(&req.tx_id)
     .bind(&req.lock_root_hash)
     .bind(&req.proposer_pkh)
-    .bind(&status_str)
+    .bind(if req.draft { ProposalStatus::Draft } else { ProposalStatus::Pending }.as_str())
     .bind(req.threshold)
     .bind(&req.raw_tx_json)
     .bind(&req.notes_json)
@@ -214,9 +667,19 @@ async fn create_proposal(
     .bind(&seeds_json)
     .bind(&now)
     .bind(&now)
-    .execute(&pool)
+    .bind(&expires_at)
+    .bind(req.proposal_type.as_str())
+    .execute(&mut *conn)
     .await?;
     
+    for hash in &req.additional_lock_root_hashes {
+        sqlx::query("INSERT INTO proposal_locks (proposal_id, lock_root_hash) VALUES (?, ?)")
+            .bind(&proposal_id)
+            .bind(hash)
+            .execute(&mut *conn)
+            .await?;
+    }
+    
     // Record proposer's signature
     sqlx::query(
         "INSERT INTO proposal_signatures (proposal_id, signer_pkh, signed_tx_json, signed_at) VALUES (?, ?, ?, ?)"
@@ -225,7 +688,7 @@ async fn create_proposal(
     .bind(&req.proposer_pkh)
     .bind(&req.proposer_signed_tx_json)
     .bind(&now)
-    .execute(&pool)
+    .execute(&mut *conn)
     .await?;
     
     // Check if ready (same logic as sign_proposal)
@@ -233,298 +696,2142 @@ async fn create_proposal(
         "SELECT COUNT(*) FROM proposal_signatures WHERE proposal_id = ?"
     )
     .bind(&proposal_id)
-    .fetch_one(&pool)
+    .fetch_one(&mut *conn)
     .await?;
     
-    if sig_count >= req.threshold {
-        let ready_status = serde_json::to_string(&ProposalStatus::Ready)
-            .unwrap_or_else(|_| "ready".to_string())
-            .trim_matches('"')
-            .to_string();
+    let mut ready = false;
+    if !req.draft && sig_count >= req.threshold && wallet_policies_met(&mut *conn, &proposal_id).await? {
         sqlx::query("UPDATE proposals SET status = ?, updated_at = ? WHERE id = ?")
-            .bind(&ready_status)
+            .bind(ProposalStatus::Ready.as_str())
             .bind(&now)
             .bind(&proposal_id)
-            .execute(&pool)
+            .execute(&mut *conn)
             .await?;
+        ready = true;
     }
     
-    Ok(Json(CreateProposalResponse {
+    Ok(CreatedProposal {
         id: proposal_id,
-        tx_id: req.tx_id,
-    }))
+        tx_id: req.tx_id.clone(),
+        ready,
+        signatures_collected: sig_count,
+    })
+}
+
+pub(crate) fn emit_proposal_created(events: &dyn EventSink, req: &CreateProposalRequest, created: &CreatedProposal) {
+    if req.draft {
+        tracing::info!(proposal_id = %created.id, lock_root_hash = %req.lock_root_hash, "Draft proposal saved");
+        return;
+    }
+    tracing::info!(
+        proposal_id = %created.id,
+        lock_root_hash = %req.lock_root_hash,
+        threshold = req.threshold,
+        "Proposal created"
+    );
+    events.emit(ProposalEvent::Created {
+        proposal_id: created.id.clone(),
+        tx_id: req.tx_id.clone(),
+        lock_root_hash: req.lock_root_hash.clone(),
+        proposer_pkh: req.proposer_pkh.clone(),
+    });
+    if created.ready {
+        tracing::info!(proposal_id = %created.id, signatures = created.signatures_collected, "Proposal ready");
+        events.emit(ProposalEvent::Ready {
+            proposal_id: created.id.clone(),
+            lock_root_hash: req.lock_root_hash.clone(),
+        });
+    }
+}
+
+/// Bounds stored size and guarantees the field is parseable JSON for downstream consumers
+pub(crate) fn validate_json_field(name: &str, value: &str, max_bytes: usize) -> Result<(), AppError> {
+    if value.len() > max_bytes {
+        return Err(AppError::InvalidInput(
+            format!("{} is too large ({} bytes, max {})", name, value.len(), max_bytes)
+        ));
+    }
+    serde_json::from_str::<serde::de::IgnoredAny>(value)
+        .map_err(|e| AppError::InvalidInput(format!("{} is not valid JSON: {}", name, e)))?;
+    Ok(())
+}
+
+pub(crate) fn seed_responses(seeds: Vec<SeedSummary>, config: &Config) -> Vec<SeedResponse> {
+    seeds.into_iter().map(|seed| SeedResponse {
+        amount_display: config.format_nicks(seed.amount_nicks),
+        recipient: seed.recipient,
+        amount_nicks: seed.amount_nicks,
+    }).collect()
+}
+
+/// Decodes a JSON column written by this server. Corrupt data is logged with the record id
+/// and reported through the returned flag, so callers can say so instead of showing an empty value.
+pub(crate) fn decode_stored_json<T: serde::de::DeserializeOwned + Default>(
+    table: &str,
+    id: &str,
+    column: &str,
+    json: &str,
+) -> (T, bool) {
+    match serde_json::from_str(json) {
+        Ok(value) => (value, false),
+        Err(e) => {
+            tracing::warn!(table, id, column, error = %e, "Stored JSON failed to parse");
+            (T::default(), true)
+        }
+    }
+}
+
+/// Smallest `max_fee_nicks` declared by any spend condition (accepts numbers or
+/// protobuf-style numeric strings); None when no condition caps the fee
+fn fee_cap_nicks(spend_conditions: &serde_json::Value) -> Option<i64> {
+    let conditions = match spend_conditions {
+        serde_json::Value::Array(items) => items.as_slice(),
+        other => std::slice::from_ref(other),
+    };
+    conditions
+        .iter()
+        .filter_map(|c| c.get("max_fee_nicks"))
+        .filter_map(|v| v.as_i64().or_else(|| v.as_str()?.parse().ok()))
+        .min()
+}
+
+/// Rejects proposals whose implied fee (inputs minus outputs) exceeds a declared fee cap
+fn validate_fee_cap(req: &CreateProposalRequest) -> Result<(), AppError> {
+    let spend_conditions: serde_json::Value = serde_json::from_str(&req.spend_conditions_json)
+        .map_err(|e| AppError::InvalidInput(format!("spend_conditions_json is not valid JSON: {}", e)))?;
+    let Some(cap) = fee_cap_nicks(&spend_conditions) else {
+        return Ok(());
+    };
+    
+    let outputs = req.seeds.iter().fold(0i64, |sum, seed| sum.saturating_add(seed.amount_nicks));
+    let fee = req.total_input_nicks.saturating_sub(outputs);
+    if fee > cap {
+        return Err(AppError::InvalidInput(
+            format!("Transaction fee of {} nicks exceeds the spend condition fee cap of {} nicks", fee, cap)
+        ));
+    }
+    Ok(())
+}
+
+/// Rejects seeds paying anyone outside the wallet's recipient allow-list, if it has one.
+/// Change back to the wallet's own lock is always allowed. Both sides are compared trimmed,
+/// as add_allowed_recipient stores them
+async fn check_allowed_recipients(executor: impl SqliteExecutor<'_>, lock_root_hash: &LockRootHash, seeds: &[SeedSummary]) -> Result<(), AppError> {
+    let allowed: Vec<String> = sqlx::query_scalar(
+        "SELECT recipient FROM lock_allowed_recipients WHERE lock_root_hash = ?"
+    )
+    .bind(lock_root_hash)
+    .fetch_all(executor)
+    .await?;
+    
+    if allowed.is_empty() {
+        return Ok(());
+    }
+    let allowed_seed = |recipient: &str| {
+        is_change_to(recipient, lock_root_hash) || allowed.iter().any(|a| a == recipient)
+    };
+    if let Some(seed) = seeds.iter().find(|s| !allowed_seed(s.recipient.trim())) {
+        return Err(AppError::InvalidInput(
+            format!("Recipient {} is not on this wallet's allow-list", seed.recipient)
+        ));
+    }
+    Ok(())
+}
+
+/// Clients send recipients as `<destination type>:<destination>`, so change back to the
+/// wallet is `lockroot:<hash>`; the bare hash is accepted too
+fn is_change_to(recipient: &str, lock_root_hash: &LockRootHash) -> bool {
+    recipient.strip_prefix("lockroot:").unwrap_or(recipient) == lock_root_hash.as_str()
+}
+
+/// Wallets a multi-wallet proposal spends from besides its primary `lock_root_hash`
+async fn additional_lock_root_hashes(executor: impl SqliteExecutor<'_>, proposal_id: &str) -> Result<Vec<LockRootHash>, AppError> {
+    Ok(sqlx::query_scalar(
+        "SELECT lock_root_hash FROM proposal_locks WHERE proposal_id = ? ORDER BY lock_root_hash"
+    )
+    .bind(proposal_id)
+    .fetch_all(executor)
+    .await?)
+}
+
+/// Signing policy beyond the caller's plain signature count: every required signer of every
+/// wallet spent from has signed, and for multi-wallet proposals each wallet has reached its
+/// threshold from its own participants' signatures (the primary against the proposal's
+/// threshold, the others against their own)
+async fn wallet_policies_met(executor: impl SqliteExecutor<'_>, proposal_id: &str) -> Result<bool, AppError> {
+    let unmet: i64 = sqlx::query_scalar(
+        "SELECT
+             (SELECT COUNT(*) FROM proposal_locks pl
+              INNER JOIN locks l ON l.lock_root_hash = pl.lock_root_hash
+              WHERE pl.proposal_id = ?1
+                AND (SELECT COUNT(*) FROM proposal_signatures s
+                     INNER JOIN lock_participants lp ON lp.pkh = s.signer_pkh AND lp.lock_root_hash = pl.lock_root_hash
+                     WHERE s.proposal_id = ?1) < l.threshold)
+           + (SELECT COUNT(*) FROM proposals p
+              WHERE p.id = ?1
+                AND EXISTS (SELECT 1 FROM proposal_locks pl WHERE pl.proposal_id = p.id)
+                AND (SELECT COUNT(*) FROM proposal_signatures s
+                     INNER JOIN lock_participants lp ON lp.pkh = s.signer_pkh AND lp.lock_root_hash = p.lock_root_hash
+                     WHERE s.proposal_id = p.id) < p.threshold)
+           + (SELECT COUNT(*) FROM lock_participants lp
+              WHERE lp.required = 1
+                AND lp.lock_root_hash IN (SELECT lock_root_hash FROM proposals WHERE id = ?1
+                                          UNION SELECT lock_root_hash FROM proposal_locks WHERE proposal_id = ?1)
+                AND NOT EXISTS (SELECT 1 FROM proposal_signatures s WHERE s.proposal_id = ?1 AND s.signer_pkh = lp.pkh))"
+    )
+    .bind(proposal_id)
+    .fetch_one(executor)
+    .await?;
+    Ok(unmet == 0)
+}
+
+/// Whether the PKH participates in any wallet the proposal spends from
+async fn is_proposal_participant(executor: impl SqliteExecutor<'_>, proposal_id: &str, pkh: &Pkh) -> Result<bool, AppError> {
+    let found: Option<i32> = sqlx::query_scalar(
+        "SELECT 1 FROM lock_participants
+         WHERE pkh = ? AND lock_root_hash IN (
+             SELECT lock_root_hash FROM proposals WHERE id = ?
+             UNION SELECT lock_root_hash FROM proposal_locks WHERE proposal_id = ?)
+         LIMIT 1"
+    )
+    .bind(pkh)
+    .bind(proposal_id)
+    .bind(proposal_id)
+    .fetch_optional(executor)
+    .await?;
+    Ok(found.is_some())
+}
+
+/// Defensive integrity check: a PKH listed twice in a wallet would be masked by the
+/// `LIMIT 1` participant lookup. Migration 018 rejects such rows at the schema level
+async fn ensure_single_participant_row(executor: impl SqliteExecutor<'_>, proposal_id: &str, pkh: &Pkh) -> Result<(), AppError> {
+    let duplicated_in: Option<String> = sqlx::query_scalar(
+        "SELECT lock_root_hash FROM lock_participants
+         WHERE pkh = ? AND lock_root_hash IN (
+             SELECT lock_root_hash FROM proposals WHERE id = ?
+             UNION SELECT lock_root_hash FROM proposal_locks WHERE proposal_id = ?)
+         GROUP BY lock_root_hash HAVING COUNT(*) > 1
+         LIMIT 1"
+    )
+    .bind(pkh)
+    .bind(proposal_id)
+    .bind(proposal_id)
+    .fetch_optional(executor)
+    .await?;
+    
+    match duplicated_in {
+        Some(lock_root_hash) => Err(AppError::Internal(
+            format!("PKH {} is listed more than once in wallet {}", pkh, lock_root_hash)
+        )),
+        None => Ok(()),
+    }
 }
 
-async fn list_proposals(
+#[utoipa::path(
+    get, path = "", tag = "proposals",
+    params(
+        ListProposalsQuery,
+        ("If-Modified-Since" = Option<String>, Header, description = "Last-Modified from a previous response"),
+    ),
+    responses(
+        (status = 200, body = Vec<ProposalResponse>, headers(("Last-Modified" = String), ("X-Total-Count" = i64))),
+        (status = 304, description = "No matching proposal changed since If-Modified-Since"),
+        (status = 400, body = ErrorBody),
+    )
+)]
+pub(crate) async fn list_proposals(
     State(pool): State<DbPool>,
-    Query(params): Query<ListProposalsQuery>,
-) -> Result<Json<Vec<ProposalResponse>>, AppError> {
+    State(config): State<Arc<Config>>,
+    FormQuery(params): FormQuery<ListProposalsQuery>,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
+    let page = Pagination::from_query(params.limit, params.offset, &config)?;
+    
     // Build query based on filters
-    let proposals: Vec<Proposal> = if let Some(pkh) = &params.pkh {
-        // Get proposals for wallets where this PKH is a participant
-        sqlx::query_as::<_, Proposal>(
-            "SELECT DISTINCT p.* FROM proposals p
-             INNER JOIN lock_participants lp ON p.lock_root_hash = lp.lock_root_hash
-             WHERE lp.pkh = ?
-             ORDER BY p.created_at DESC"
-        )
-        .bind(pkh)
-        .fetch_all(&pool)
-        .await?
+    let mut query = String::from("SELECT DISTINCT p.* FROM proposals p");
+    let mut conditions = Vec::new();
+    let mut binds: Vec<String> = Vec::new();
+    if let Some(pkh) = &params.pkh {
+        // Get proposals spending from any wallet where this PKH is a participant
+        conditions.push(
+            "EXISTS (SELECT 1 FROM lock_participants lp WHERE lp.pkh = ?
+                     AND (lp.lock_root_hash = p.lock_root_hash
+                          OR lp.lock_root_hash IN (SELECT pl.lock_root_hash FROM proposal_locks pl WHERE pl.proposal_id = p.id)))"
+        );
+        binds.push(pkh.to_string());
     } else if let Some(lock_root_hash) = &params.lock_root_hash {
-        sqlx::query_as::<_, Proposal>(
-            "SELECT * FROM proposals WHERE lock_root_hash = ? ORDER BY created_at DESC"
-        )
-        .bind(lock_root_hash)
-        .fetch_all(&pool)
-        .await?
-    } else {
-        sqlx::query_as::<_, Proposal>(
-            "SELECT * FROM proposals ORDER BY created_at DESC"
-        )
-        .fetch_all(&pool)
-        .await?
-    };
+        conditions.push(
+            "(p.lock_root_hash = ? OR p.id IN (SELECT pl.proposal_id FROM proposal_locks pl WHERE pl.lock_root_hash = ?))"
+        );
+        binds.push(lock_root_hash.to_string());
+        binds.push(lock_root_hash.to_string());
+    }
+    // Drafts are listed only for their proposer
+    match &params.pkh {
+        Some(pkh) => {
+            conditions.push("(p.status != 'draft' OR p.proposer_pkh = ?)");
+            binds.push(pkh.to_string());
+        }
+        None => conditions.push("p.status != 'draft'"),
+    }
+    // The filters above pick the wallets in scope; the ones below only narrow within it
+    let scope_conditions = conditions.len();
+    let scope_binds = binds.len();
     
     // Filter by status if provided
-    let proposals: Vec<Proposal> = if let Some(status_str) = &params.status {
+    if let Some(status_str) = &params.status {
         let filter_status: ProposalStatus = status_str.parse()
             .map_err(|e| AppError::InvalidInput(format!("Invalid status: {} - {}", status_str, e)))?;
-        proposals.into_iter().filter(|p| p.status == filter_status).collect()
-    } else {
-        proposals
+        conditions.push("p.status = ?");
+        binds.push(filter_status.as_str().to_string());
+    }
+    if let Some(type_str) = &params.proposal_type {
+        let filter_type: ProposalType = type_str.parse().map_err(AppError::InvalidInput)?;
+        conditions.push("p.proposal_type = ?");
+        binds.push(filter_type.as_str().to_string());
+    }
+    let mut since_window = None;
+    if let Some(since) = &params.since {
+        let window = parse_relative_duration(since).ok_or_else(|| AppError::InvalidInput(
+            format!("Invalid since: {} (expected a duration like 24h, 7d or 30d)", since)
+        ))?;
+        let cutoff = chrono::Utc::now().checked_sub_signed(window)
+            .ok_or_else(|| AppError::InvalidInput(format!("since is out of range: {}", since)))?;
+        conditions.push("p.created_at >= ?");
+        binds.push(format_rfc3339(cutoff));
+        since_window = Some((window, format_rfc3339(cutoff)));
+    }
+    // A delegated signature counts for the delegator, which is what signer_pkh records
+    for pkh in &params.signed_by {
+        conditions.push("EXISTS (SELECT 1 FROM proposal_signatures s WHERE s.proposal_id = p.id AND s.signer_pkh = ?)");
+        binds.push(pkh.to_string());
+    }
+    for pkh in &params.not_signed_by {
+        conditions.push("NOT EXISTS (SELECT 1 FROM proposal_signatures s WHERE s.proposal_id = p.id AND s.signer_pkh = ?)");
+        binds.push(pkh.to_string());
+    }
+    if !conditions.is_empty() {
+        query.push_str(" WHERE ");
+        query.push_str(&conditions.join(" AND "));
+    }
+    
+    // Last-Modified has to move when the set shrinks as well as when a member changes, so it
+    // is the newest of: any update in the wallets in scope (a proposal leaving a status or
+    // signature filter bumps updated_at on the way out), the moment the newest proposal older
+    // than the `since` window slid out of it, and the proposer's last draft discard
+    let max_query = format!(
+        "SELECT MAX(p.updated_at), MAX(CASE WHEN p.created_at < ? THEN p.created_at END) FROM proposals p WHERE {}",
+        conditions[..scope_conditions].join(" AND ")
+    );
+    let mut max_updated_query = sqlx::query_as::<_, (Option<String>, Option<String>)>(&max_query)
+        .bind(since_window.as_ref().map(|(_, cutoff)| cutoff));
+    for value in &binds[..scope_binds] {
+        max_updated_query = max_updated_query.bind(value);
+    }
+    let (updated_at, aged_out_created_at) = max_updated_query.fetch_one(&pool.read).await?;
+    let aged_out_at = since_window
+        .zip(aged_out_created_at.as_deref().and_then(parse_timestamp))
+        .map(|((window, _), created_at)| created_at + window);
+    let discarded_at = match &params.pkh {
+        Some(pkh) => sqlx::query_scalar::<_, String>("SELECT discarded_at FROM draft_discards WHERE proposer_pkh = ?")
+            .bind(pkh)
+            .fetch_optional(&pool.read)
+            .await?,
+        None => None,
     };
+    let last_modified = [updated_at.as_deref().and_then(parse_timestamp), aged_out_at, discarded_at.as_deref().and_then(parse_timestamp)]
+        .into_iter()
+        .flatten()
+        .max()
+        .map(http_date);
+    if let Some(last_modified) = &last_modified
+        && not_modified_since(&headers, last_modified)
+    {
+        return Ok((StatusCode::NOT_MODIFIED, [(header::LAST_MODIFIED, last_modified.clone())]).into_response());
+    }
+    
+    // The window count is taken before LIMIT/OFFSET, so each row carries the size of the whole
+    // filtered set. The filters are all EXISTS/IN subqueries, so no row repeats and DISTINCT
+    // (which would apply after the window) isn't needed here
+    let page_query = query.replacen("SELECT DISTINCT p.*", "SELECT p.*, COUNT(*) OVER () AS total_count", 1)
+        + " ORDER BY p.created_at DESC, p.id DESC LIMIT ? OFFSET ?";
+    let mut proposals_query = sqlx::query_as::<_, Counted<Proposal>>(&page_query);
+    for value in &binds {
+        proposals_query = proposals_query.bind(value);
+    }
+    let rows = proposals_query
+        .bind(page.limit)
+        .bind(page.offset)
+        .fetch_all(&pool.read)
+        .await?;
+    let (proposals, total) = Counted::split(rows);
     
-    // Get signatures for each proposal
+    // An empty page only proves the set is empty when it starts at the beginning; past the end
+    // the total needs its own count
+    let total = match total {
+        Some(total) => total,
+        None if page.offset == 0 => 0,
+        None => {
+            let count_query = query.replacen("SELECT DISTINCT p.*", "SELECT COUNT(*)", 1);
+            let mut total_query = sqlx::query_scalar::<_, i64>(&count_query);
+            for value in &binds {
+                total_query = total_query.bind(value);
+            }
+            total_query.fetch_one(&pool.read).await?
+        }
+    };
+    
+    let responses = proposal_responses(&pool, &config, proposals, params.include_signers.unwrap_or(true)).await?;
+    let mut response = Json(responses).into_response();
+    response.headers_mut().insert(TOTAL_COUNT_HEADER, HeaderValue::from(total));
+    if let Some(last_modified) = last_modified.and_then(|value| HeaderValue::from_str(&value).ok()) {
+        response.headers_mut().insert(header::LAST_MODIFIED, last_modified);
+    }
+    Ok(response)
+}
+
+/// List entries for the given proposals, with their signers or just a count when the
+/// caller doesn't need them
+async fn proposal_responses(
+    pool: &DbPool,
+    config: &Config,
+    proposals: Vec<Proposal>,
+    include_signers: bool,
+) -> Result<Vec<ProposalResponse>, AppError> {
     let mut responses = Vec::new();
     for proposal in proposals {
-        let signatures: Vec<ProposalSignature> = sqlx::query_as::<_, ProposalSignature>(
-            "SELECT * FROM proposal_signatures WHERE proposal_id = ?"
-        )
-        .bind(&proposal.id)
-        .fetch_all(&pool)
-        .await?;
-        
-        let signers: Vec<String> = signatures.iter().map(|s| s.signer_pkh.clone()).collect();
-        let seeds: Vec<SeedSummary> = serde_json::from_str(&proposal.seeds_json).unwrap_or_default();
+        let (signatures_collected, signers) = if include_signers {
+            let signers: Vec<Pkh> = sqlx::query_scalar(
+                "SELECT signer_pkh FROM proposal_signatures WHERE proposal_id = ? ORDER BY signer_pkh"
+            )
+            .bind(&proposal.id)
+            .fetch_all(&pool.read)
+            .await?;
+            (signers.len() as i32, Some(signers))
+        } else {
+            let count: i32 = sqlx::query_scalar(
+                "SELECT COUNT(*) FROM proposal_signatures WHERE proposal_id = ?"
+            )
+            .bind(&proposal.id)
+            .fetch_one(&pool.read)
+            .await?;
+            (count, None)
+        };
+        let (seeds, seeds_corrupt) = decode_stored_json::<Vec<SeedSummary>>("proposals", &proposal.id, "seeds_json", &proposal.seeds_json);
+        let additional_lock_root_hashes = additional_lock_root_hashes(&pool.read, &proposal.id).await?;
         
         responses.push(ProposalResponse {
             id: proposal.id,
             tx_id: proposal.tx_id,
             lock_root_hash: proposal.lock_root_hash,
+            additional_lock_root_hashes,
             proposer_pkh: proposal.proposer_pkh,
-            status: serde_json::to_string(&proposal.status)
-                .unwrap_or_default()
-                .trim_matches('"')
-                .to_string(),
+            proposal_type: proposal.proposal_type,
+            status: proposal.status,
             threshold: proposal.threshold,
-            signatures_collected: signers.len() as i32,
+            signatures_collected,
             total_input_nicks: proposal.total_input_nicks,
-            seeds,
+            total_input_display: config.format_nicks(proposal.total_input_nicks),
+            seeds: seed_responses(seeds, config),
+            seeds_corrupt,
             signers,
             created_at: proposal.created_at,
             updated_at: proposal.updated_at,
+            expires_at: proposal.expires_at,
         });
     }
     
-    Ok(Json(responses))
+    Ok(responses)
 }
 
+#[utoipa::path(
+    get, path = "/{id}", tag = "proposals",
+    params(
+        ("id" = String, Path, description = "Proposal ID"),
+        ProposalViewQuery,
+        ("If-None-Match" = Option<String>, Header, description = "ETag from a previous response"),
+    ),
+    security((), ("admin_token" = [])),
+    responses(
+        (status = 200, body = ProposalDetailResponse, headers(("ETag" = String))),
+        (status = 304, description = "Proposal unchanged since the given ETag"),
+        (status = 404, body = ErrorBody),
+    )
+)]
 async fn get_proposal(
+    admin: Option<AdminAuth>,
     State(pool): State<DbPool>,
+    State(config): State<Arc<Config>>,
     Path(id): Path<String>,
-) -> Result<Json<ProposalDetailResponse>, AppError> {
+    Query(view): Query<ProposalViewQuery>,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
     let proposal: Proposal = sqlx::query_as::<_, Proposal>(
         "SELECT * FROM proposals WHERE id = ?"
     )
     .bind(&id)
-    .fetch_optional(&pool)
+    .fetch_optional(&pool.read)
     .await?
     .ok_or_else(|| AppError::NotFound(format!("Proposal {} not found", id)))?;
     
+    check_draft_access(&proposal, view.viewer_pkh.as_ref(), admin.is_some())?;
+    let show_tx_data = tx_data_visible(&config, admin.as_ref());
+    proposal_detail_response(&pool, &config, proposal, &view, &headers, show_tx_data).await
+}
+
+#[utoipa::path(
+    get, path = "/by-tx/{tx_id}", tag = "proposals",
+    params(
+        ("tx_id" = String, Path, description = "Transaction ID of the proposal"),
+        ProposalViewQuery,
+        ("If-None-Match" = Option<String>, Header, description = "ETag from a previous response"),
+    ),
+    security((), ("admin_token" = [])),
+    responses(
+        (status = 200, body = ProposalDetailResponse, headers(("ETag" = String))),
+        (status = 304, description = "Proposal unchanged since the given ETag"),
+        (status = 404, body = ErrorBody),
+    )
+)]
+async fn get_proposal_by_tx(
+    admin: Option<AdminAuth>,
+    State(pool): State<DbPool>,
+    State(config): State<Arc<Config>>,
+    Path(tx_id): Path<String>,
+    Query(view): Query<ProposalViewQuery>,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
+    let proposal: Proposal = sqlx::query_as::<_, Proposal>(
+        "SELECT * FROM proposals WHERE tx_id = ?"
+    )
+    .bind(&tx_id)
+    .fetch_optional(&pool.read)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("No proposal with tx_id {}", tx_id)))?;
+    
+    check_draft_access(&proposal, view.viewer_pkh.as_ref(), admin.is_some())?;
+    let show_tx_data = tx_data_visible(&config, admin.as_ref());
+    proposal_detail_response(&pool, &config, proposal, &view, &headers, show_tx_data).await
+}
+
+/// Drafts are private to their proposer, as in listings: anyone else gets the 404 a missing
+/// proposal would, so draft ids (predictable with DETERMINISTIC_PROPOSAL_IDS) can't be probed.
+/// The proposer reads a draft by passing their PKH as viewer_pkh; the admin token sees all
+fn check_draft_access(proposal: &Proposal, viewer_pkh: Option<&Pkh>, admin: bool) -> Result<(), AppError> {
+    draft_access(&proposal.id, &proposal.status, &proposal.proposer_pkh, viewer_pkh, admin)
+}
+
+fn draft_access(id: &str, status: &ProposalStatus, proposer_pkh: &Pkh, viewer_pkh: Option<&Pkh>, admin: bool) -> Result<(), AppError> {
+    if *status == ProposalStatus::Draft && !admin && viewer_pkh != Some(proposer_pkh) {
+        return Err(AppError::NotFound(format!("Proposal {} not found", id)));
+    }
+    Ok(())
+}
+
+/// Transaction payloads (raw_tx_json, signed_tx_json) are what RAW_TX_LINK_SECRET protects:
+/// with it set, only admin callers get them outside a signed raw-tx link
+fn tx_data_visible(config: &Config, admin: Option<&AdminAuth>) -> bool {
+    config.raw_tx_link_secret.is_none() || admin.is_some()
+}
+
+/// Detail body shared by the by-id and by-tx lookups, honoring If-None-Match
+async fn proposal_detail_response(
+    pool: &DbPool,
+    config: &Config,
+    proposal: Proposal,
+    view: &ProposalViewQuery,
+    headers: &HeaderMap,
+    show_tx_data: bool,
+) -> Result<Response, AppError> {
     // Get signatures with their data
     let db_signatures: Vec<ProposalSignature> = sqlx::query_as::<_, ProposalSignature>(
-        "SELECT * FROM proposal_signatures WHERE proposal_id = ?"
+        "SELECT * FROM proposal_signatures WHERE proposal_id = ? ORDER BY signed_at, signer_pkh"
     )
     .bind(&proposal.id)
-    .fetch_all(&pool)
+    .fetch_all(&pool.read)
     .await?;
     
-    let signers: Vec<String> = db_signatures.iter().map(|s| s.signer_pkh.clone()).collect();
-    let signatures: Vec<SignatureEntry> = db_signatures.iter().map(|s| SignatureEntry {
-        signer_pkh: s.signer_pkh.clone(),
-        signed_tx_json: s.signed_tx_json.clone(),
-        signed_at: s.signed_at.clone(),
-    }).collect();
+    // ETag changes whenever a signature is added or the status transitions (both bump updated_at),
+    // and differs between renderings of the same state
+    let expired = proposal.expires_at.as_ref().is_some_and(|expires_at| *expires_at <= now_rfc3339());
+    let etag = proposal_etag(&proposal.updated_at, db_signatures.len(), &ProposalRendering {
+        show_tx_data,
+        include_signatures: view.include_signatures.unwrap_or(true),
+        viewer_pkh: view.viewer_pkh.as_deref(),
+        expired,
+    });
+    // The admin token changes the body (show_tx_data), so shared caches must key on it
+    let vary = (header::VARY, HeaderValue::from_static("authorization"));
+    if etag_matches(headers, &etag) {
+        return Ok((StatusCode::NOT_MODIFIED, [(header::ETAG, etag)], [vary]).into_response());
+    }
+    
+    // Deterministic ordering so identical state yields identical bodies: signatures by
+    // signing time, PKH lists alphabetically
+    let mut signers: Vec<Pkh> = db_signatures.iter().map(|s| s.signer_pkh.clone()).collect();
+    signers.sort();
+    let signatures: Option<Vec<SignatureEntry>> = view.include_signatures.unwrap_or(true).then(|| {
+        db_signatures.iter().map(|s| SignatureEntry {
+            signer_pkh: s.signer_pkh.clone(),
+            signed_tx_json: show_tx_data.then(|| s.signed_tx_json.clone()),
+            signed_at: s.signed_at.clone(),
+            delegate_pkh: s.delegate_pkh.clone(),
+            signer_note: s.signer_note.clone(),
+        }).collect()
+    });
     
-    // Get participants
-    let participants: Vec<String> = sqlx::query_scalar(
-        "SELECT pkh FROM lock_participants WHERE lock_root_hash = ?"
+    // Get participants across every wallet spent from; someone in several counts once
+    let additional_lock_root_hashes = additional_lock_root_hashes(&pool.read, &proposal.id).await?;
+    let participant_rows: Vec<(Pkh, bool, bool)> = sqlx::query_as(
+        "SELECT pkh, MAX(active), MAX(required) FROM lock_participants
+         WHERE lock_root_hash = ? OR lock_root_hash IN (SELECT lock_root_hash FROM proposal_locks WHERE proposal_id = ?)
+         GROUP BY pkh ORDER BY pkh"
     )
     .bind(&proposal.lock_root_hash)
-    .fetch_all(&pool)
+    .bind(&proposal.id)
+    .fetch_all(&pool.read)
     .await?;
     
-    let seeds: Vec<SeedSummary> = serde_json::from_str(&proposal.seeds_json).unwrap_or_default();
+    let (seeds, seeds_corrupt) = decode_stored_json::<Vec<SeedSummary>>("proposals", &proposal.id, "seeds_json", &proposal.seeds_json);
+    let max_possible_signatures = participant_rows.iter()
+        .filter(|(pkh, active, _)| *active || signers.contains(pkh))
+        .count() as i32;
+    let inactive_participants = participant_rows.iter()
+        .filter(|(_, active, _)| !active)
+        .map(|(pkh, _, _)| pkh.clone())
+        .collect();
+    let unmet_required_signers = participant_rows.iter()
+        .filter(|(pkh, _, required)| *required && !signers.contains(pkh))
+        .map(|(pkh, _, _)| pkh.clone())
+        .collect();
+    let participants: Vec<Pkh> = participant_rows.into_iter().map(|(pkh, _, _)| pkh).collect();
+    
+    // Same conditions verify_signature enforces, so the sign button matches what signing accepts
+    let viewer_pkh = view.viewer_pkh.as_deref();
+    let viewer_has_signed = viewer_pkh.map(|viewer| signers.iter().any(|s| s == viewer));
+    let viewer_can_sign = viewer_pkh.map(|viewer| {
+        participants.iter().any(|p| p == viewer)
+            && proposal.status == ProposalStatus::Pending
+            && proposal.expires_at.as_ref().is_none_or(|expires_at| *expires_at > now_rfc3339())
+            && viewer_has_signed != Some(true)
+    });
     
-    Ok(Json(ProposalDetailResponse {
+    let body = Json(ProposalDetailResponse {
         id: proposal.id,
         tx_id: proposal.tx_id,
         lock_root_hash: proposal.lock_root_hash,
+        additional_lock_root_hashes,
         proposer_pkh: proposal.proposer_pkh,
-        status: serde_json::to_string(&proposal.status)
-            .unwrap_or_default()
-            .trim_matches('"')
-            .to_string(),
+        proposal_type: proposal.proposal_type,
+        status: proposal.status,
         threshold: proposal.threshold,
         signatures_collected: signers.len() as i32,
-        raw_tx_json: proposal.raw_tx_json,
+        raw_tx_json: show_tx_data.then_some(proposal.raw_tx_json),
         notes_json: proposal.notes_json,
         spend_conditions_json: proposal.spend_conditions_json,
         total_input_nicks: proposal.total_input_nicks,
-        seeds,
+        total_input_display: config.format_nicks(proposal.total_input_nicks),
+        seeds: seed_responses(seeds, config),
+        seeds_corrupt,
         signers,
         signatures,
         participants,
+        inactive_participants,
+        unmet_required_signers,
+        max_possible_signatures,
+        threshold_reachable: max_possible_signatures >= proposal.threshold,
         created_at: proposal.created_at,
         updated_at: proposal.updated_at,
-    }))
+        expires_at: proposal.expires_at,
+        viewer_has_signed,
+        viewer_can_sign,
+    });
+    
+    Ok(([(header::ETAG, etag)], [vary], body).into_response())
 }
 
-async fn sign_proposal(
-    State(pool): State<DbPool>,
-    Path(id): Path<String>,
-    Json(req): Json<SignProposalRequest>,
-) -> Result<Json<SignProposalResponse>, AppError> {
+/// Request-dependent parts of a detail body
+struct ProposalRendering<'a> {
+    show_tx_data: bool,
+    include_signatures: bool,
+    viewer_pkh: Option<&'a str>,
+    expired: bool, // viewer_can_sign turns false at expiry without updated_at moving
+}
+
+fn proposal_etag(updated_at: &str, sig_count: usize, rendering: &ProposalRendering) -> String {
+    format!(
+        "\"{}-{}-{}{}{}-{}\"",
+        updated_at,
+        sig_count,
+        if rendering.show_tx_data { 't' } else { 'r' },
+        if rendering.include_signatures { 's' } else { 'n' },
+        if rendering.expired { 'x' } else { 'o' },
+        rendering.viewer_pkh.unwrap_or_default(),
+    )
+}
+
+/// Returns true if any entity tag in If-None-Match matches (or is the `*` wildcard)
+fn etag_matches(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .map(|tag| tag.trim().trim_start_matches("W/"))
+        .any(|tag| tag == "*" || tag == etag)
+}
+
+fn parse_timestamp(ts: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::parse_from_rfc3339(ts).ok().map(|parsed| parsed.with_timezone(&chrono::Utc))
+}
+
+/// Timestamp as an HTTP-date; sub-second precision is dropped
+fn http_date(ts: chrono::DateTime<chrono::Utc>) -> String {
+    ts.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
+
+/// True when If-Modified-Since is at or after `last_modified`. HTTP dates have one-second
+/// resolution, so a change later in the same second as the client's copy goes unnoticed
+/// until the next one
+fn not_modified_since(headers: &HeaderMap, last_modified: &str) -> bool {
+    let since = headers
+        .get(header::IF_MODIFIED_SINCE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| chrono::DateTime::parse_from_rfc2822(v).ok());
+    let modified = chrono::DateTime::parse_from_rfc2822(last_modified).ok();
+    matches!((since, modified), (Some(since), Some(modified)) if modified <= since)
+}
+
+/// Participants (of any wallet spent from) who signed or are still active, for a `proposals p`
+/// row; inactive participants who haven't signed can't be counted on to reach threshold
+const MAX_POSSIBLE_SIGNATURES_SQL: &str =
+    "(SELECT COUNT(DISTINCT lp.pkh) FROM lock_participants lp
+      WHERE (lp.lock_root_hash = p.lock_root_hash
+             OR lp.lock_root_hash IN (SELECT pl.lock_root_hash FROM proposal_locks pl WHERE pl.proposal_id = p.id))
+        AND (lp.active = 1 OR EXISTS (
+            SELECT 1 FROM proposal_signatures s WHERE s.proposal_id = p.id AND s.signer_pkh = lp.pkh)))";
+
+/// Lightweight readiness check for pollers; skips the tx/notes JSON blobs entirely
+#[utoipa::path(
+    get, path = "/{id}/status", tag = "proposals",
+    params(("id" = String, Path, description = "Proposal ID"), ViewerQuery),
+    security((), ("admin_token" = [])),
+    responses((status = 200, body = ProposalStatusResponse), (status = 404, body = ErrorBody))
+)]
+async fn get_proposal_status(
+    admin: Option<AdminAuth>,
+    State(pool): State<DbPool>,
+    Path(id): Path<String>,
+    Query(viewer): Query<ViewerQuery>,
+) -> Result<Json<ProposalStatusResponse>, AppError> {
+    let (status, proposer_pkh, threshold, signatures_collected, max_possible_signatures): (String, Pkh, i32, i32, i32) = sqlx::query_as(&format!(
+        "SELECT p.status, p.proposer_pkh, p.threshold,
+                (SELECT COUNT(*) FROM proposal_signatures s WHERE s.proposal_id = p.id),
+                {}
+         FROM proposals p WHERE p.id = ?",
+        MAX_POSSIBLE_SIGNATURES_SQL
+    ))
+    .bind(&id)
+    .fetch_optional(&pool.read)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Proposal {} not found", id)))?;
+    let status: ProposalStatus = status.parse().map_err(AppError::Internal)?;
+    draft_access(&id, &status, &proposer_pkh, viewer.viewer_pkh.as_ref(), admin.is_some())?;
+    
+    let ready_to_broadcast = status == ProposalStatus::Ready;
+    
+    Ok(Json(ProposalStatusResponse {
+        status,
+        signatures_collected,
+        threshold,
+        ready_to_broadcast,
+        max_possible_signatures,
+        threshold_reachable: max_possible_signatures >= threshold,
+    }))
+}
+
+/// Unsigned transaction plus collected signatures, for broadcaster bots that don't need
+/// seeds, participants or comments. Only available once the proposal is ready. With
+/// RAW_TX_LINK_SECRET set, callers need a signed link or the admin token
+#[utoipa::path(
+    get, path = "/{id}/raw-tx", tag = "proposals",
+    params(("id" = String, Path, description = "Proposal ID"), RawTxLinkQuery),
+    security((), ("admin_token" = [])),
+    responses(
+        (status = 200, body = RawTxResponse),
+        (status = 400, body = ErrorBody),
+        (status = 401, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    )
+)]
+async fn get_raw_tx(
+    admin: Option<AdminAuth>,
+    State(pool): State<DbPool>,
+    State(config): State<Arc<Config>>,
+    Path(id): Path<String>,
+    Query(link): Query<RawTxLinkQuery>,
+) -> Result<Json<RawTxResponse>, AppError> {
+    if let Some(secret) = &config.raw_tx_link_secret
+        && admin.is_none()
+    {
+        let (Some(expires), Some(sig)) = (link.expires, link.sig.as_deref()) else {
+            return Err(AppError::Unauthorized("raw-tx requires a signed link or the admin token".to_string()));
+        };
+        raw_tx_links::verify(secret, &id, expires, sig, chrono::Utc::now().timestamp())?;
+    }
+    
+    let (status, raw_tx_json): (String, String) = sqlx::query_as(
+        "SELECT status, raw_tx_json FROM proposals WHERE id = ?"
+    )
+    .bind(&id)
+    .fetch_optional(&pool.read)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Proposal {} not found", id)))?;
+    
+    if status.parse::<ProposalStatus>() != Ok(ProposalStatus::Ready) {
+        return Err(AppError::InvalidInput(
+            format!("Proposal {} is not ready to broadcast (status: {})", id, status)
+        ));
+    }
+    
+    let signatures: Vec<SignatureEntry> = sqlx::query_as::<_, ProposalSignature>(
+        "SELECT * FROM proposal_signatures WHERE proposal_id = ? ORDER BY signed_at, signer_pkh"
+    )
+    .bind(&id)
+    .fetch_all(&pool.read)
+    .await?
+    .into_iter()
+    .map(|s| SignatureEntry {
+        signer_pkh: s.signer_pkh,
+        signed_tx_json: Some(s.signed_tx_json),
+        signed_at: s.signed_at,
+        delegate_pkh: s.delegate_pkh,
+        signer_note: s.signer_note,
+    })
+    .collect();
+    
+    Ok(Json(RawTxResponse { raw_tx_json, signatures }))
+}
+
+/// Mints a short-lived link to this proposal's raw-tx, so an isolated broadcaster can fetch
+/// it without holding the admin token
+#[utoipa::path(
+    post, path = "/{id}/raw-tx-link", tag = "proposals",
+    params(("id" = String, Path, description = "Proposal ID")),
+    security(("admin_token" = [])),
+    responses(
+        (status = 200, body = RawTxLinkResponse),
+        (status = 400, body = ErrorBody),
+        (status = 401, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    )
+)]
+async fn create_raw_tx_link(
+    _admin: AdminAuth,
+    State(pool): State<DbPool>,
+    State(config): State<Arc<Config>>,
+    Path(id): Path<String>,
+) -> Result<Json<RawTxLinkResponse>, AppError> {
+    let secret = config.raw_tx_link_secret.as_deref().ok_or_else(|| {
+        AppError::InvalidInput("Signed raw-tx links are disabled (RAW_TX_LINK_SECRET is not set)".to_string())
+    })?;
+    let exists: Option<i32> = sqlx::query_scalar("SELECT 1 FROM proposals WHERE id = ?")
+        .bind(&id)
+        .fetch_optional(&pool.read)
+        .await?;
+    if exists.is_none() {
+        return Err(AppError::NotFound(format!("Proposal {} not found", id)));
+    }
+    
+    // Links carry whole seconds, so expires_at is reported at that resolution too
+    let expires = chrono::Utc::now().timestamp() + config.raw_tx_link_ttl_secs as i64;
+    let expires_at = chrono::DateTime::from_timestamp(expires, 0)
+        .map(format_rfc3339)
+        .ok_or_else(|| AppError::Internal(format!("Link expiry out of range: {}", expires)))?;
+    let sig = raw_tx_links::sign(secret, &id, expires);
+    tracing::info!(proposal_id = %id, expires_at = %expires_at, "Raw-tx link minted");
+    
+    Ok(Json(RawTxLinkResponse {
+        url: format!("/api/proposals/{}/raw-tx?expires={}&sig={}", id, expires, sig),
+        expires_at,
+    }))
+}
+
+/// Signatures one page at a time, for wallets too large to inline them in the detail.
+/// signed_tx_json is left out under the same rule as the detail view
+#[utoipa::path(
+    get, path = "/{id}/signatures", tag = "proposals",
+    params(("id" = String, Path, description = "Proposal ID"), SignaturesQuery),
+    security((), ("admin_token" = [])),
+    responses(
+        (status = 200, body = SignaturePageResponse),
+        (status = 400, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    )
+)]
+async fn list_signatures(
+    admin: Option<AdminAuth>,
+    State(pool): State<DbPool>,
+    State(config): State<Arc<Config>>,
+    Path(id): Path<String>,
+    Query(params): Query<SignaturesQuery>,
+) -> Result<Json<SignaturePageResponse>, AppError> {
+    let limit = Pagination::from_query(params.limit, None, &config)?.limit;
+    // Cursor is "<signed_at>|<signer_pkh>" of the last signature on the previous page
+    let cursor = params.cursor.as_deref()
+        .map(|c| c.split_once('|').ok_or_else(|| AppError::InvalidInput("Invalid cursor".to_string())))
+        .transpose()?;
+    
+    let (status, proposer_pkh): (String, Pkh) = sqlx::query_as(
+        "SELECT status, proposer_pkh FROM proposals WHERE id = ?"
+    )
+    .bind(&id)
+    .fetch_optional(&pool.read)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Proposal {} not found", id)))?;
+    let status: ProposalStatus = status.parse().map_err(AppError::Internal)?;
+    draft_access(&id, &status, &proposer_pkh, params.viewer_pkh.as_ref(), admin.is_some())?;
+    
+    let rows: Vec<ProposalSignature> = sqlx::query_as::<_, ProposalSignature>(
+        "SELECT * FROM proposal_signatures
+         WHERE proposal_id = ?1 AND (?2 IS NULL OR signed_at > ?2 OR (signed_at = ?2 AND signer_pkh > ?3))
+         ORDER BY signed_at, signer_pkh
+         LIMIT ?4"
+    )
+    .bind(&id)
+    .bind(cursor.map(|(ts, _)| ts))
+    .bind(cursor.map(|(_, pkh)| pkh))
+    .bind(limit + 1)
+    .fetch_all(&pool.read)
+    .await?;
+    
+    // One extra row was fetched to tell whether another page exists
+    let has_more = rows.len() as i64 > limit;
+    let show_tx_data = tx_data_visible(&config, admin.as_ref());
+    let signatures: Vec<SignatureEntry> = rows.into_iter().take(limit as usize).map(|s| SignatureEntry {
+        signer_pkh: s.signer_pkh,
+        signed_tx_json: show_tx_data.then_some(s.signed_tx_json),
+        signed_at: s.signed_at,
+        delegate_pkh: s.delegate_pkh,
+        signer_note: s.signer_note,
+    }).collect();
+    
+    let next_cursor = if has_more {
+        signatures.last().map(|s| format!("{}|{}", s.signed_at, s.signer_pkh))
+    } else {
+        None
+    };
+    Ok(Json(SignaturePageResponse { signatures, next_cursor }))
+}
+
+/// Re-checks every collected signature before broadcast: the signer must still be a
+/// participant of a wallet the proposal spends from and the signed tx must be well-formed.
+/// Signed payloads are opaque to the gateway, so there is no cryptographic check here.
+/// `valid` also needs the proposal itself to still be open for broadcast
+#[utoipa::path(
+    get, path = "/{id}/verify", tag = "proposals",
+    params(("id" = String, Path, description = "Proposal ID"), ViewerQuery),
+    security((), ("admin_token" = [])),
+    responses((status = 200, body = VerifyProposalResponse), (status = 404, body = ErrorBody))
+)]
+async fn verify_proposal(
+    admin: Option<AdminAuth>,
+    State(pool): State<DbPool>,
+    Path(id): Path<String>,
+    Query(viewer): Query<ViewerQuery>,
+) -> Result<Json<VerifyProposalResponse>, AppError> {
+    let mut conn = pool.read.acquire().await?;
+    let proposal: Proposal = sqlx::query_as::<_, Proposal>(
+        "SELECT * FROM proposals WHERE id = ?"
+    )
+    .bind(&id)
+    .fetch_optional(&mut *conn)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Proposal {} not found", id)))?;
+    check_draft_access(&proposal, viewer.viewer_pkh.as_ref(), admin.is_some())?;
+    
+    let signatures: Vec<ProposalSignature> = sqlx::query_as::<_, ProposalSignature>(
+        "SELECT * FROM proposal_signatures WHERE proposal_id = ? ORDER BY signed_at, signer_pkh"
+    )
+    .bind(&id)
+    .fetch_all(&mut *conn)
+    .await?;
+    
+    let mut invalid_signers = Vec::new();
+    for signature in &signatures {
+        let well_formed = serde_json::from_str::<serde::de::IgnoredAny>(&signature.signed_tx_json).is_ok();
+        if !well_formed || !is_proposal_participant(&mut *conn, &id, &signature.signer_pkh).await? {
+            invalid_signers.push(signature.signer_pkh.clone());
+        }
+    }
+    
+    let valid_count = (signatures.len() - invalid_signers.len()) as i64;
+    // Same readiness rule as signing: required signers and every wallet's own threshold too
+    let threshold_met = valid_count >= i64::from(proposal.threshold)
+        && wallet_policies_met(&mut *conn, &id).await?;
+    let expired = proposal.expires_at.as_ref().is_some_and(|expires_at| *expires_at <= now_rfc3339());
+    let open = matches!(proposal.status, ProposalStatus::Pending | ProposalStatus::Ready) && !expired;
+    Ok(Json(VerifyProposalResponse {
+        valid: open && threshold_met && invalid_signers.is_empty(),
+        open,
+        threshold_met,
+        invalid_signers,
+    }))
+}
+
+/// The participant a signature counts toward: the signer, or the delegator when the signer
+/// holds an active delegation in a wallet the proposal spends from
+async fn counted_signer<'a>(
+    conn: &mut SqliteConnection,
+    proposal: &Proposal,
+    signer_pkh: &'a Pkh,
+    on_behalf_of: Option<&'a Pkh>,
+) -> Result<&'a Pkh, AppError> {
+    let Some(from_pkh) = on_behalf_of else {
+        return Ok(signer_pkh);
+    };
+    let delegated: Option<i32> = sqlx::query_scalar(
+        "SELECT 1 FROM delegations
+         WHERE from_pkh = ? AND to_pkh = ? AND revoked_at IS NULL AND expires_at > ?
+           AND lock_root_hash IN (
+               SELECT lock_root_hash FROM proposals WHERE id = ?
+               UNION SELECT lock_root_hash FROM proposal_locks WHERE proposal_id = ?)
+         LIMIT 1"
+    )
+    .bind(from_pkh)
+    .bind(signer_pkh)
+    .bind(now_rfc3339())
+    .bind(&proposal.id)
+    .bind(&proposal.id)
+    .fetch_optional(&mut *conn)
+    .await?;
+    
+    if delegated.is_none() {
+        return Err(AppError::InvalidInput(
+            format!("PKH {} has no active delegation from {} for this proposal", signer_pkh, from_pkh)
+        ));
+    }
+    Ok(from_pkh)
+}
+
+/// Checks shared by signing and its preflight: the proposal is still collecting signatures,
+/// the signer belongs to the wallet and hasn't signed yet, and the signed tx is well-formed
+async fn verify_signature(
+    conn: &mut SqliteConnection,
+    proposal: &Proposal,
+    signer_pkh: &Pkh,
+    signed_tx_json: &str,
+) -> Result<(), AppError> {
+    if proposal.status != ProposalStatus::Pending {
+        return Err(AppError::InvalidInput(
+            format!("Cannot sign proposal with status: {:?}", proposal.status)
+        ));
+    }
+    
+    if let Some(expires_at) = &proposal.expires_at
+        && *expires_at <= now_rfc3339()
+    {
+        return Err(AppError::InvalidInput(format!("Proposal expired at {}", expires_at)));
+    }
+    
+    if !is_proposal_participant(&mut *conn, &proposal.id, signer_pkh).await? {
+        return Err(AppError::InvalidInput(
+            format!("PKH {} is not a participant of this wallet", signer_pkh)
+        ));
+    }
+    ensure_single_participant_row(&mut *conn, &proposal.id, signer_pkh).await?;
+    
+    // Check if already signed
+    let already_signed: Option<String> = sqlx::query_scalar(
+        "SELECT signer_pkh FROM proposal_signatures WHERE proposal_id = ? AND signer_pkh = ?"
+    )
+    .bind(&proposal.id)
+    .bind(signer_pkh)
+    .fetch_optional(&mut *conn)
+    .await?;
+    
+    if already_signed.is_some() {
+        return Err(AppError::InvalidInput(
+            format!("PKH {} has already signed this proposal", signer_pkh)
+        ));
+    }
+    
+    serde_json::from_str::<serde::de::IgnoredAny>(signed_tx_json)
+        .map_err(|e| AppError::InvalidInput(format!("signed_tx_json is not valid JSON: {}", e)))?;
+    Ok(())
+}
+
+/// Broadcast queue: proposals that reached threshold but haven't been broadcast, oldest first
+/// so none starve behind newer ones
+#[utoipa::path(
+    get, path = "/ready", tag = "proposals",
+    params(ReadyProposalsQuery),
+    responses((status = 200, body = Vec<ReadyProposalResponse>), (status = 400, body = ErrorBody))
+)]
+async fn list_ready_proposals(
+    State(pool): State<DbPool>,
+    State(config): State<Arc<Config>>,
+    Query(params): Query<ReadyProposalsQuery>,
+) -> Result<Json<Vec<ReadyProposalResponse>>, AppError> {
+    let page = Pagination::from_query(params.limit, params.offset, &config)?;
+    
+    let mut conditions = vec!["p.status = 'ready'"];
+    let mut binds: Vec<String> = Vec::new();
+    if let Some(pkh) = &params.pkh {
+        conditions.push(
+            "EXISTS (SELECT 1 FROM lock_participants lp WHERE lp.pkh = ?
+                     AND (lp.lock_root_hash = p.lock_root_hash
+                          OR lp.lock_root_hash IN (SELECT pl.lock_root_hash FROM proposal_locks pl WHERE pl.proposal_id = p.id)))"
+        );
+        binds.push(pkh.to_string());
+    }
+    if let Some(lock_root_hash) = &params.lock_root_hash {
+        conditions.push(
+            "(p.lock_root_hash = ? OR p.id IN (SELECT pl.proposal_id FROM proposal_locks pl WHERE pl.lock_root_hash = ?))"
+        );
+        binds.push(lock_root_hash.to_string());
+        binds.push(lock_root_hash.to_string());
+    }
+    let query = format!(
+        "SELECT p.id, p.tx_id, p.lock_root_hash, p.created_at, p.threshold,
+                (SELECT COUNT(*) FROM proposal_signatures s WHERE s.proposal_id = p.id),
+                {}
+         FROM proposals p
+         WHERE {}
+         ORDER BY p.created_at ASC, p.id ASC LIMIT ? OFFSET ?",
+        MAX_POSSIBLE_SIGNATURES_SQL,
+        conditions.join(" AND ")
+    );
+    
+    let mut rows_query = sqlx::query_as::<_, (String, String, LockRootHash, String, i32, i32, i32)>(&query);
+    for value in &binds {
+        rows_query = rows_query.bind(value);
+    }
+    let rows = rows_query
+        .bind(page.limit)
+        .bind(page.offset)
+        .fetch_all(&pool.read)
+        .await?;
+    
+    let responses = rows.into_iter().map(
+        |(id, tx_id, lock_root_hash, created_at, threshold, signatures_collected, max_possible_signatures)| {
+            ReadyProposalResponse {
+                id,
+                tx_id,
+                lock_root_hash,
+                created_at,
+                status: ProposalStatusResponse {
+                    status: ProposalStatus::Ready,
+                    signatures_collected,
+                    threshold,
+                    ready_to_broadcast: true,
+                    max_possible_signatures,
+                    threshold_reachable: max_possible_signatures >= threshold,
+                },
+            }
+        },
+    ).collect();
+    
+    Ok(Json(responses))
+}
+
+/// Longest lookahead accepted by the expiring-soon query
+const MAX_EXPIRING_WINDOW_DAYS: i64 = 30;
+
+/// Pending proposals whose expiry falls within the window, soonest first, for reminders
+#[utoipa::path(
+    get, path = "/expiring", tag = "proposals",
+    params(ExpiringProposalsQuery),
+    responses((status = 200, body = Vec<ProposalResponse>), (status = 400, body = ErrorBody))
+)]
+async fn list_expiring_proposals(
+    State(pool): State<DbPool>,
+    State(config): State<Arc<Config>>,
+    Query(params): Query<ExpiringProposalsQuery>,
+) -> Result<Json<Vec<ProposalResponse>>, AppError> {
+    let page = Pagination::from_query(params.limit, params.offset, &config)?;
+    let within = params.within.as_deref().unwrap_or_default();
+    let window = parse_relative_duration(within)
+        .filter(|w| *w <= chrono::Duration::days(MAX_EXPIRING_WINDOW_DAYS))
+        .ok_or_else(|| AppError::InvalidInput(format!(
+            "Invalid within: {:?} (expected a duration like 6h or 2d, at most {}d)",
+            within, MAX_EXPIRING_WINDOW_DAYS
+        )))?;
+    let now = chrono::Utc::now();
+    
+    let mut conditions = vec!["p.status = 'pending'", "p.expires_at > ?", "p.expires_at <= ?"];
+    let mut binds: Vec<String> = vec![format_rfc3339(now), format_rfc3339(now + window)];
+    if let Some(pkh) = &params.pkh {
+        conditions.push(
+            "EXISTS (SELECT 1 FROM lock_participants lp WHERE lp.pkh = ?
+                     AND (lp.lock_root_hash = p.lock_root_hash
+                          OR lp.lock_root_hash IN (SELECT pl.lock_root_hash FROM proposal_locks pl WHERE pl.proposal_id = p.id)))"
+        );
+        binds.push(pkh.to_string());
+    }
+    if let Some(lock_root_hash) = &params.lock_root_hash {
+        conditions.push(
+            "(p.lock_root_hash = ? OR p.id IN (SELECT pl.proposal_id FROM proposal_locks pl WHERE pl.lock_root_hash = ?))"
+        );
+        binds.push(lock_root_hash.to_string());
+        binds.push(lock_root_hash.to_string());
+    }
+    let query = format!(
+        "SELECT p.* FROM proposals p WHERE {} ORDER BY p.expires_at ASC, p.id ASC LIMIT ? OFFSET ?",
+        conditions.join(" AND ")
+    );
+    
+    let mut proposals_query = sqlx::query_as::<_, Proposal>(&query);
+    for value in &binds {
+        proposals_query = proposals_query.bind(value);
+    }
+    let proposals: Vec<Proposal> = proposals_query
+        .bind(page.limit)
+        .bind(page.offset)
+        .fetch_all(&pool.read)
+        .await?;
+    
+    Ok(Json(proposal_responses(&pool, &config, proposals, true).await?))
+}
+
+/// Preflight for co-signers: runs the sign path's verification without recording anything
+/// (the replay nonce is neither checked nor consumed)
+#[utoipa::path(
+    post, path = "/{id}/sign/validate", tag = "proposals",
+    params(("id" = String, Path, description = "Proposal ID")),
+    request_body = ValidateSignatureRequest,
+    responses((status = 200, body = ValidateSignatureResponse), (status = 404, body = ErrorBody))
+)]
+async fn validate_signature(
+    State(pool): State<DbPool>,
+    Path(id): Path<String>,
+    JsonBody(req): JsonBody<ValidateSignatureRequest>,
+) -> Result<Json<ValidateSignatureResponse>, AppError> {
+    let mut conn = pool.read.acquire().await?;
+    let proposal: Proposal = sqlx::query_as::<_, Proposal>(
+        "SELECT * FROM proposals WHERE id = ?"
+    )
+    .bind(&id)
+    .fetch_optional(&mut *conn)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Proposal {} not found", id)))?;
+    
+    let verified = match counted_signer(&mut conn, &proposal, &req.signer_pkh, req.on_behalf_of.as_ref()).await {
+        Ok(counted_pkh) => verify_signature(&mut conn, &proposal, counted_pkh, &req.signed_tx_json).await,
+        Err(e) => Err(e),
+    };
+    let response = match verified {
+        Ok(()) => ValidateSignatureResponse { valid: true, reason: None },
+        Err(AppError::InvalidInput(reason)) => ValidateSignatureResponse { valid: false, reason: Some(reason) },
+        Err(e) => return Err(e),
+    };
+    Ok(Json(response))
+}
+
+/// Longest accepted signer_note; it labels a device or person, not a document
+const MAX_SIGNER_NOTE_BYTES: usize = 256;
+
+/// Trimmed note, None when absent or blank
+fn signer_note(note: Option<&str>) -> Result<Option<&str>, AppError> {
+    let Some(note) = note.map(str::trim).filter(|note| !note.is_empty()) else {
+        return Ok(None);
+    };
+    if note.len() > MAX_SIGNER_NOTE_BYTES {
+        return Err(AppError::InvalidInput(
+            format!("signer_note is too long ({} bytes, max {})", note.len(), MAX_SIGNER_NOTE_BYTES)
+        ));
+    }
+    Ok(Some(note))
+}
+
+#[utoipa::path(
+    post, path = "/{id}/sign", tag = "proposals",
+    params(("id" = String, Path, description = "Proposal ID")),
+    request_body = SignProposalRequest,
+    responses(
+        (status = 200, body = SignProposalResponse),
+        (status = 400, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    )
+)]
+async fn sign_proposal(
+    State(pool): State<DbPool>,
+    State(events): State<Arc<dyn EventSink>>,
+    Path(id): Path<String>,
+    JsonBody(req): JsonBody<SignProposalRequest>,
+) -> Result<Json<SignProposalResponse>, AppError> {
+    let signer_note = signer_note(req.signer_note.as_deref())?;
+    
+    // IMMEDIATE takes the write lock up front, so concurrent signers are serialized from the
+    // status check through the count and status update
+    let mut tx = pool.write.begin_with("BEGIN IMMEDIATE").await?;
+    
     // Get proposal
     let proposal: Proposal = sqlx::query_as::<_, Proposal>(
         "SELECT * FROM proposals WHERE id = ?"
     )
     .bind(&id)
-    .fetch_optional(&pool)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Proposal {} not found", id)))?;
+    
+    let counted_pkh = counted_signer(&mut tx, &proposal, &req.signer_pkh, req.on_behalf_of.as_ref()).await?;
+    verify_signature(&mut tx, &proposal, counted_pkh, &req.signed_tx_json).await?;
+    
+    // Replay protection: only advance the stored nonce if this one is strictly newer
+    let now = now_rfc3339();
+    let nonce_accepted = sqlx::query(
+        "INSERT INTO sign_nonces (proposal_id, signer_pkh, last_nonce, updated_at) VALUES (?, ?, ?, ?)
+         ON CONFLICT (proposal_id, signer_pkh) DO UPDATE
+         SET last_nonce = excluded.last_nonce, updated_at = excluded.updated_at
+         WHERE excluded.last_nonce > sign_nonces.last_nonce"
+    )
+    .bind(&proposal.id)
+    .bind(&req.signer_pkh)
+    .bind(req.nonce)
+    .bind(&now)
+    .execute(&mut *tx)
+    .await?
+    .rows_affected() > 0;
+    
+    if !nonce_accepted {
+        return Err(AppError::InvalidInput(
+            format!("Stale or replayed sign request: nonce {} is not newer than the last accepted", req.nonce)
+        ));
+    }
+    
+    // Record signature with the signed tx data; a delegated one counts toward the delegator
+    let delegate_pkh = req.on_behalf_of.is_some().then_some(&req.signer_pkh);
+    sqlx::query(
+        "INSERT INTO proposal_signatures (proposal_id, signer_pkh, signed_tx_json, signed_at, delegate_pkh, signer_note) VALUES (?, ?, ?, ?, ?, ?)"
+    )
+    .bind(&proposal.id)
+    .bind(counted_pkh)
+    .bind(&req.signed_tx_json)
+    .bind(&now)
+    .bind(delegate_pkh)
+    .bind(signer_note)
+    .execute(&mut *tx)
+    .await?;
+    
+    // Count signatures
+    let sig_count: i32 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM proposal_signatures WHERE proposal_id = ?"
+    )
+    .bind(&proposal.id)
+    .fetch_one(&mut *tx)
+    .await?;
+    
+    // Update status if ready; the status guard keeps Ready a one-time transition
+    let mut ready_to_broadcast = false;
+    if sig_count >= proposal.threshold && wallet_policies_met(&mut *tx, &proposal.id).await? {
+        ready_to_broadcast = sqlx::query("UPDATE proposals SET status = ?, updated_at = ? WHERE id = ? AND status = 'pending'")
+            .bind(ProposalStatus::Ready.as_str())
+            .bind(&now)
+            .bind(&proposal.id)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected() > 0;
+    } else {
+        sqlx::query("UPDATE proposals SET updated_at = ? WHERE id = ?")
+            .bind(&now)
+            .bind(&proposal.id)
+            .execute(&mut *tx)
+            .await?;
+    }
+    tx.commit().await?;
+    
+    tracing::info!(
+        proposal_id = %proposal.id,
+        signer_pkh = %counted_pkh,
+        delegate_pkh = ?delegate_pkh,
+        signatures = sig_count,
+        "Signature added"
+    );
+    events.emit(ProposalEvent::Signed {
+        proposal_id: proposal.id.clone(),
+        signer_pkh: counted_pkh.clone(),
+        signatures_collected: sig_count,
+    });
+    if ready_to_broadcast {
+        tracing::info!(proposal_id = %proposal.id, signatures = sig_count, "Proposal ready");
+        events.emit(ProposalEvent::Ready {
+            proposal_id: proposal.id.clone(),
+            lock_root_hash: proposal.lock_root_hash.clone(),
+        });
+    }
+    
+    Ok(Json(SignProposalResponse {
+        success: true,
+        signatures_collected: sig_count,
+        ready_to_broadcast,
+        on_behalf_of: req.on_behalf_of.clone(),
+    }))
+}
+
+/// Fixes a mistyped threshold without recreating the proposal. Only allowed while it is a
+/// draft or pending and nobody but the proposer has signed; readiness is re-evaluated afterwards
+#[utoipa::path(
+    patch, path = "/{id}", tag = "proposals",
+    params(("id" = String, Path, description = "Proposal ID")),
+    request_body = UpdateProposalRequest,
+    responses(
+        (status = 200, body = UpdateProposalResponse),
+        (status = 400, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    )
+)]
+async fn update_proposal(
+    State(pool): State<DbPool>,
+    State(events): State<Arc<dyn EventSink>>,
+    Path(id): Path<String>,
+    JsonBody(req): JsonBody<UpdateProposalRequest>,
+) -> Result<Json<UpdateProposalResponse>, AppError> {
+    let threshold = req.threshold
+        .ok_or_else(|| AppError::InvalidInput("Nothing to update: threshold is the only editable field".to_string()))?;
+    
+    // Same write lock as signing, so nobody can sign between the checks and the update
+    let mut tx = pool.write.begin_with("BEGIN IMMEDIATE").await?;
+    
+    let proposal: Proposal = sqlx::query_as::<_, Proposal>(
+        "SELECT * FROM proposals WHERE id = ?"
+    )
+    .bind(&id)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Proposal {} not found", id)))?;
+    
+    if proposal.proposer_pkh != req.proposer_pkh {
+        return Err(AppError::InvalidInput("Only the proposer may edit a proposal".to_string()));
+    }
+    if !matches!(proposal.status, ProposalStatus::Draft | ProposalStatus::Pending) {
+        return Err(AppError::InvalidInput(
+            format!("Cannot edit proposal with status: {:?}", proposal.status)
+        ));
+    }
+    
+    let sig_count: i32 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM proposal_signatures WHERE proposal_id = ?"
+    )
+    .bind(&proposal.id)
+    .fetch_one(&mut *tx)
+    .await?;
+    
+    // Only the proposer's own signature may be present; after a withdrawal or a reassignment
+    // the one remaining signature can belong to someone else
+    let others_signed: bool = sqlx::query_scalar(
+        "SELECT EXISTS (SELECT 1 FROM proposal_signatures WHERE proposal_id = ? AND signer_pkh != ?)"
+    )
+    .bind(&proposal.id)
+    .bind(&proposal.proposer_pkh)
+    .fetch_one(&mut *tx)
+    .await?;
+    
+    if others_signed {
+        return Err(AppError::InvalidInput(
+            "Cannot edit the threshold after other participants have signed".to_string()
+        ));
+    }
+    
+    let participant_count: i32 = sqlx::query_scalar(
+        "SELECT COUNT(DISTINCT pkh) FROM lock_participants
+         WHERE lock_root_hash = ? OR lock_root_hash IN (SELECT lock_root_hash FROM proposal_locks WHERE proposal_id = ?)"
+    )
+    .bind(&proposal.lock_root_hash)
+    .bind(&proposal.id)
+    .fetch_one(&mut *tx)
+    .await?;
+    
+    if threshold < 1 || threshold > participant_count {
+        return Err(AppError::InvalidInput(
+            format!("threshold must be between 1 and {} (the number of participants)", participant_count)
+        ));
+    }
+    
+    let now = now_rfc3339();
+    sqlx::query("UPDATE proposals SET threshold = ?, updated_at = ? WHERE id = ?")
+        .bind(threshold)
+        .bind(&now)
+        .bind(&proposal.id)
+        .execute(&mut *tx)
+        .await?;
+    
+    sqlx::query(
+        "INSERT INTO proposal_events (id, proposal_id, event_type, actor, reason, created_at) VALUES (?, ?, 'threshold_updated', ?, ?, ?)"
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(&proposal.id)
+    .bind(&req.proposer_pkh)
+    .bind(format!("threshold {} -> {}", proposal.threshold, threshold))
+    .bind(&now)
+    .execute(&mut *tx)
+    .await?;
+    
+    // A lowered threshold may already be met by the proposer's signature
+    let mut ready_to_broadcast = false;
+    if sig_count >= threshold && wallet_policies_met(&mut *tx, &proposal.id).await? {
+        ready_to_broadcast = sqlx::query("UPDATE proposals SET status = ? WHERE id = ? AND status = 'pending'")
+            .bind(ProposalStatus::Ready.as_str())
+            .bind(&proposal.id)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected() > 0;
+    }
+    tx.commit().await?;
+    
+    tracing::info!(
+        proposal_id = %proposal.id,
+        old_threshold = proposal.threshold,
+        threshold,
+        "Proposal threshold updated"
+    );
+    if ready_to_broadcast {
+        tracing::info!(proposal_id = %proposal.id, signatures = sig_count, "Proposal ready");
+        events.emit(ProposalEvent::Ready {
+            proposal_id: proposal.id.clone(),
+            lock_root_hash: proposal.lock_root_hash.clone(),
+        });
+    }
+    
+    Ok(Json(UpdateProposalResponse {
+        id: proposal.id,
+        threshold,
+        signatures_collected: sig_count,
+        ready_to_broadcast,
+    }))
+}
+
+/// Turns a draft into a pending proposal; co-signers see it and are notified from here on
+#[utoipa::path(
+    post, path = "/{id}/publish", tag = "proposals",
+    params(("id" = String, Path, description = "Proposal ID")),
+    request_body = PublishProposalRequest,
+    responses(
+        (status = 200, body = ProposalStatusResponse),
+        (status = 400, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    )
+)]
+async fn publish_proposal(
+    State(pool): State<DbPool>,
+    State(events): State<Arc<dyn EventSink>>,
+    Path(id): Path<String>,
+    JsonBody(req): JsonBody<PublishProposalRequest>,
+) -> Result<Json<ProposalStatusResponse>, AppError> {
+    let mut tx = pool.write.begin_with("BEGIN IMMEDIATE").await?;
+    
+    let proposal: Proposal = sqlx::query_as::<_, Proposal>(
+        "SELECT * FROM proposals WHERE id = ?"
+    )
+    .bind(&id)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Proposal {} not found", id)))?;
+    
+    if proposal.proposer_pkh != req.proposer_pkh {
+        return Err(AppError::InvalidInput("Only the proposer may publish a proposal".to_string()));
+    }
+    if proposal.status != ProposalStatus::Draft {
+        return Err(AppError::InvalidInput(
+            format!("Cannot publish proposal with status: {:?}", proposal.status)
+        ));
+    }
+    let now = now_rfc3339();
+    if proposal.expires_at.as_ref().is_some_and(|expires_at| *expires_at <= now) {
+        return Err(AppError::InvalidInput(
+            "The draft expired before it was published; create it again with a later expires_at".to_string()
+        ));
+    }
+    
+    // The proposer's signature alone may already meet the threshold (same logic as creation)
+    let sig_count: i32 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM proposal_signatures WHERE proposal_id = ?"
+    )
+    .bind(&proposal.id)
+    .fetch_one(&mut *tx)
+    .await?;
+    let ready = sig_count >= proposal.threshold && wallet_policies_met(&mut *tx, &proposal.id).await?;
+    let status = if ready { ProposalStatus::Ready } else { ProposalStatus::Pending };
+    
+    sqlx::query("UPDATE proposals SET status = ?, updated_at = ? WHERE id = ?")
+        .bind(status.as_str())
+        .bind(&now)
+        .bind(&proposal.id)
+        .execute(&mut *tx)
+        .await?;
+    
+    let max_possible_signatures: i32 = sqlx::query_scalar(&format!(
+        "SELECT {} FROM proposals p WHERE p.id = ?",
+        MAX_POSSIBLE_SIGNATURES_SQL
+    ))
+    .bind(&proposal.id)
+    .fetch_one(&mut *tx)
+    .await?;
+    tx.commit().await?;
+    
+    tracing::info!(proposal_id = %proposal.id, lock_root_hash = %proposal.lock_root_hash, "Draft proposal published");
+    events.emit(ProposalEvent::Created {
+        proposal_id: proposal.id.clone(),
+        tx_id: proposal.tx_id.clone(),
+        lock_root_hash: proposal.lock_root_hash.clone(),
+        proposer_pkh: proposal.proposer_pkh.clone(),
+    });
+    if ready {
+        tracing::info!(proposal_id = %proposal.id, signatures = sig_count, "Proposal ready");
+        events.emit(ProposalEvent::Ready {
+            proposal_id: proposal.id.clone(),
+            lock_root_hash: proposal.lock_root_hash.clone(),
+        });
+    }
+    
+    Ok(Json(ProposalStatusResponse {
+        status,
+        signatures_collected: sig_count,
+        threshold: proposal.threshold,
+        ready_to_broadcast: ready,
+        max_possible_signatures,
+        threshold_reachable: max_possible_signatures >= proposal.threshold,
+    }))
+}
+
+/// Deletes every draft the proposer still holds, e.g. when abandoning a session. Drafts were
+/// never shown to co-signers, so nothing is announced; published proposals are untouched
+#[utoipa::path(
+    delete, path = "/drafts", tag = "proposals",
+    params(DiscardDraftsQuery),
+    responses(
+        (status = 200, body = DiscardDraftsResponse),
+        (status = 400, body = ErrorBody),
+    )
+)]
+async fn discard_drafts(
+    State(pool): State<DbPool>,
+    Query(query): Query<DiscardDraftsQuery>,
+) -> Result<Json<DiscardDraftsResponse>, AppError> {
+    // Signatures, nonces, comments and events go with them (ON DELETE CASCADE)
+    let mut tx = pool.write.begin_with("BEGIN IMMEDIATE").await?;
+    let deleted = sqlx::query("DELETE FROM proposals WHERE proposer_pkh = ? AND status = ?")
+        .bind(&query.proposer_pkh)
+        .bind(ProposalStatus::Draft.as_str())
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+    // Deleted rows leave no updated_at behind, so the proposer's listing needs this to change
+    // its Last-Modified
+    if deleted > 0 {
+        sqlx::query(
+            "INSERT INTO draft_discards (proposer_pkh, discarded_at) VALUES (?, ?)
+             ON CONFLICT (proposer_pkh) DO UPDATE SET discarded_at = excluded.discarded_at"
+        )
+        .bind(&query.proposer_pkh)
+        .bind(now_rfc3339())
+        .execute(&mut *tx)
+        .await?;
+    }
+    tx.commit().await?;
+    
+    tracing::info!(proposer_pkh = %query.proposer_pkh, deleted, "Drafts discarded");
+    Ok(Json(DiscardDraftsResponse { deleted }))
+}
+
+/// Lets a co-signer take back a mistaken signature while the proposal is still pending;
+/// once the threshold is reached signatures are final, even while required signers are outstanding
+#[utoipa::path(
+    delete, path = "/{id}/sign", tag = "proposals",
+    params(("id" = String, Path, description = "Proposal ID")),
+    request_body = WithdrawSignatureRequest,
+    responses(
+        (status = 200, body = WithdrawSignatureResponse),
+        (status = 400, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    )
+)]
+async fn withdraw_signature(
+    State(pool): State<DbPool>,
+    State(events): State<Arc<dyn EventSink>>,
+    Path(id): Path<String>,
+    JsonBody(req): JsonBody<WithdrawSignatureRequest>,
+) -> Result<Json<WithdrawSignatureResponse>, AppError> {
+    // Same write lock as signing, so a withdrawal can't interleave with a signature reaching threshold
+    let mut tx = pool.write.begin_with("BEGIN IMMEDIATE").await?;
+    
+    let proposal: Proposal = sqlx::query_as::<_, Proposal>(
+        "SELECT * FROM proposals WHERE id = ?"
+    )
+    .bind(&id)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Proposal {} not found", id)))?;
+    
+    if proposal.status != ProposalStatus::Pending {
+        return Err(AppError::InvalidInput(
+            format!("Cannot withdraw a signature from a proposal with status: {:?}", proposal.status)
+        ));
+    }
+    
+    // Required signers or per-wallet policies can keep a proposal pending past its threshold;
+    // signatures are final once the count is reached all the same
+    let sig_count_before: i32 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM proposal_signatures WHERE proposal_id = ?"
+    )
+    .bind(&proposal.id)
+    .fetch_one(&mut *tx)
+    .await?;
+    if sig_count_before >= proposal.threshold {
+        return Err(AppError::InvalidInput(
+            "Cannot withdraw a signature once the threshold has been reached".to_string()
+        ));
+    }
+    
+    let removed = sqlx::query(
+        "DELETE FROM proposal_signatures WHERE proposal_id = ? AND signer_pkh = ?"
+    )
+    .bind(&proposal.id)
+    .bind(&req.signer_pkh)
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+    
+    if removed == 0 {
+        return Err(AppError::InvalidInput(
+            format!("PKH {} has not signed this proposal", req.signer_pkh)
+        ));
+    }
+    
+    let now = now_rfc3339();
+    sqlx::query(
+        "INSERT INTO proposal_events (id, proposal_id, event_type, actor, reason, created_at) VALUES (?, ?, 'signature_withdrawn', ?, ?, ?)"
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(&proposal.id)
+    .bind(&req.signer_pkh)
+    .bind(req.reason.as_deref().map(str::trim).filter(|r| !r.is_empty()))
+    .bind(&now)
+    .execute(&mut *tx)
+    .await?;
+    
+    sqlx::query("UPDATE proposals SET updated_at = ? WHERE id = ?")
+        .bind(&now)
+        .bind(&proposal.id)
+        .execute(&mut *tx)
+        .await?;
+    
+    let sig_count: i32 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM proposal_signatures WHERE proposal_id = ?"
+    )
+    .bind(&proposal.id)
+    .fetch_one(&mut *tx)
+    .await?;
+    tx.commit().await?;
+    
+    tracing::info!(
+        proposal_id = %proposal.id,
+        signer_pkh = %req.signer_pkh,
+        signatures = sig_count,
+        "Signature withdrawn"
+    );
+    events.emit(ProposalEvent::SignatureWithdrawn {
+        proposal_id: proposal.id.clone(),
+        signer_pkh: req.signer_pkh.clone(),
+        signatures_collected: sig_count,
+    });
+    
+    Ok(Json(WithdrawSignatureResponse {
+        success: true,
+        signatures_collected: sig_count,
+    }))
+}
+
+/// Break-glass recovery for proposals stuck below threshold (e.g. signatures collected
+/// out-of-band); marks the proposal ready and records who did it and why
+#[utoipa::path(
+    post, path = "/{id}/force-ready", tag = "proposals",
+    params(("id" = String, Path, description = "Proposal ID")),
+    request_body = ForceReadyRequest,
+    security(("admin_token" = [])),
+    responses(
+        (status = 200, body = ProposalStatusResponse),
+        (status = 400, body = ErrorBody),
+        (status = 401, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    )
+)]
+async fn force_ready(
+    _admin: AdminAuth,
+    State(pool): State<DbPool>,
+    State(events): State<Arc<dyn EventSink>>,
+    Path(id): Path<String>,
+    JsonBody(req): JsonBody<ForceReadyRequest>,
+) -> Result<Json<ProposalStatusResponse>, AppError> {
+    let actor = req.actor.trim();
+    let reason = req.reason.trim();
+    if actor.is_empty() || reason.is_empty() {
+        return Err(AppError::InvalidInput("actor and reason are required".to_string()));
+    }
+    
+    let mut tx = pool.write.begin_with("BEGIN IMMEDIATE").await?;
+    
+    let proposal: Proposal = sqlx::query_as::<_, Proposal>(
+        "SELECT * FROM proposals WHERE id = ?"
+    )
+    .bind(&id)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Proposal {} not found", id)))?;
+    
+    if proposal.status != ProposalStatus::Pending {
+        return Err(AppError::InvalidInput(
+            format!("Cannot force ready a proposal with status: {:?}", proposal.status)
+        ));
+    }
+    
+    let now = now_rfc3339();
+    sqlx::query("UPDATE proposals SET status = ?, updated_at = ? WHERE id = ?")
+        .bind(ProposalStatus::Ready.as_str())
+        .bind(&now)
+        .bind(&proposal.id)
+        .execute(&mut *tx)
+        .await?;
+    
+    sqlx::query(
+        "INSERT INTO proposal_events (id, proposal_id, event_type, actor, reason, created_at) VALUES (?, ?, 'forced_ready', ?, ?, ?)"
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(&proposal.id)
+    .bind(actor)
+    .bind(reason)
+    .bind(&now)
+    .execute(&mut *tx)
+    .await?;
+    
+    let (signatures_collected, max_possible_signatures): (i32, i32) = sqlx::query_as(&format!(
+        "SELECT (SELECT COUNT(*) FROM proposal_signatures s WHERE s.proposal_id = p.id), {}
+         FROM proposals p WHERE p.id = ?",
+        MAX_POSSIBLE_SIGNATURES_SQL
+    ))
+    .bind(&proposal.id)
+    .fetch_one(&mut *tx)
+    .await?;
+    tx.commit().await?;
+    
+    tracing::warn!(
+        proposal_id = %proposal.id,
+        actor = %actor,
+        reason = %reason,
+        signatures = signatures_collected,
+        threshold = proposal.threshold,
+        "Proposal forced ready by admin"
+    );
+    events.emit(ProposalEvent::ForcedReady {
+        proposal_id: proposal.id.clone(),
+        lock_root_hash: proposal.lock_root_hash.clone(),
+        actor: actor.to_string(),
+        reason: reason.to_string(),
+    });
+    
+    Ok(Json(ProposalStatusResponse {
+        status: ProposalStatus::Ready,
+        signatures_collected,
+        threshold: proposal.threshold,
+        ready_to_broadcast: true,
+        max_possible_signatures,
+        threshold_reachable: max_possible_signatures >= proposal.threshold,
+    }))
+}
+
+/// Account recovery: hands an open proposal to another participant when the proposer's
+/// account is compromised or retired. Collected signatures are kept
+#[utoipa::path(
+    post, path = "/{id}/reassign-proposer", tag = "proposals",
+    params(("id" = String, Path, description = "Proposal ID")),
+    request_body = ReassignProposerRequest,
+    security(("admin_token" = [])),
+    responses(
+        (status = 200, body = ReassignProposerResponse),
+        (status = 400, body = ErrorBody),
+        (status = 401, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    )
+)]
+async fn reassign_proposer(
+    _admin: AdminAuth,
+    State(pool): State<DbPool>,
+    Path(id): Path<String>,
+    JsonBody(req): JsonBody<ReassignProposerRequest>,
+) -> Result<Json<ReassignProposerResponse>, AppError> {
+    let actor = req.actor.trim();
+    let reason = req.reason.trim();
+    if actor.is_empty() || reason.is_empty() {
+        return Err(AppError::InvalidInput("actor and reason are required".to_string()));
+    }
+    
+    let mut tx = pool.write.begin_with("BEGIN IMMEDIATE").await?;
+    
+    let proposal: Proposal = sqlx::query_as::<_, Proposal>(
+        "SELECT * FROM proposals WHERE id = ?"
+    )
+    .bind(&id)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Proposal {} not found", id)))?;
+    
+    if !matches!(proposal.status, ProposalStatus::Pending | ProposalStatus::Ready) {
+        return Err(AppError::InvalidInput(
+            format!("Cannot reassign the proposer of a proposal with status: {:?}", proposal.status)
+        ));
+    }
+    if proposal.proposer_pkh == req.proposer_pkh {
+        return Err(AppError::InvalidInput(
+            format!("PKH {} is already the proposer", req.proposer_pkh)
+        ));
+    }
+    if !is_proposal_participant(&mut *tx, &proposal.id, &req.proposer_pkh).await? {
+        return Err(AppError::InvalidInput(
+            format!("PKH {} is not a participant of this wallet", req.proposer_pkh)
+        ));
+    }
+    
+    let now = now_rfc3339();
+    sqlx::query("UPDATE proposals SET proposer_pkh = ?, updated_at = ? WHERE id = ?")
+        .bind(&req.proposer_pkh)
+        .bind(&now)
+        .bind(&proposal.id)
+        .execute(&mut *tx)
+        .await?;
+    
+    sqlx::query(
+        "INSERT INTO proposal_events (id, proposal_id, event_type, actor, reason, created_at) VALUES (?, ?, 'proposer_reassigned', ?, ?, ?)"
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(&proposal.id)
+    .bind(actor)
+    .bind(format!("{} -> {}: {}", proposal.proposer_pkh, req.proposer_pkh, reason))
+    .bind(&now)
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+    
+    tracing::warn!(
+        proposal_id = %proposal.id,
+        actor = %actor,
+        reason = %reason,
+        previous_proposer = %proposal.proposer_pkh,
+        proposer = %req.proposer_pkh,
+        "Proposal proposer reassigned by admin"
+    );
+    
+    Ok(Json(ReassignProposerResponse {
+        id: proposal.id,
+        previous_proposer_pkh: proposal.proposer_pkh,
+        proposer_pkh: req.proposer_pkh,
+    }))
+}
+
+#[utoipa::path(
+    get, path = "/{id}/comments", tag = "proposals",
+    params(("id" = String, Path, description = "Proposal ID"), ViewerQuery),
+    security((), ("admin_token" = [])),
+    responses((status = 200, body = Vec<CommentResponse>), (status = 404, body = ErrorBody))
+)]
+async fn list_comments(
+    admin: Option<AdminAuth>,
+    State(pool): State<DbPool>,
+    Path(id): Path<String>,
+    Query(viewer): Query<ViewerQuery>,
+) -> Result<Json<Vec<CommentResponse>>, AppError> {
+    let (status, proposer_pkh): (String, Pkh) = sqlx::query_as(
+        "SELECT status, proposer_pkh FROM proposals WHERE id = ?"
+    )
+    .bind(&id)
+    .fetch_optional(&pool.read)
     .await?
     .ok_or_else(|| AppError::NotFound(format!("Proposal {} not found", id)))?;
+    let status: ProposalStatus = status.parse().map_err(AppError::Internal)?;
+    draft_access(&id, &status, &proposer_pkh, viewer.viewer_pkh.as_ref(), admin.is_some())?;
     
-    if proposal.status != ProposalStatus::Pending {
+    let comments: Vec<ProposalComment> = sqlx::query_as::<_, ProposalComment>(
+        "SELECT * FROM proposal_comments WHERE proposal_id = ? ORDER BY created_at ASC, id ASC"
+    )
+    .bind(&id)
+    .fetch_all(&pool.read)
+    .await?;
+    
+    Ok(Json(comments.into_iter().map(|c| CommentResponse {
+        id: c.id,
+        author_pkh: c.author_pkh,
+        body: c.body,
+        created_at: c.created_at,
+    }).collect()))
+}
+
+#[utoipa::path(
+    post, path = "/{id}/comments", tag = "proposals",
+    params(("id" = String, Path, description = "Proposal ID")),
+    request_body = AddCommentRequest,
+    responses(
+        (status = 200, body = CommentResponse),
+        (status = 400, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    )
+)]
+async fn add_comment(
+    State(pool): State<DbPool>,
+    State(config): State<Arc<Config>>,
+    Path(id): Path<String>,
+    JsonBody(req): JsonBody<AddCommentRequest>,
+) -> Result<Json<CommentResponse>, AppError> {
+    let body = req.body.trim();
+    if body.is_empty() {
+        return Err(AppError::InvalidInput("Comment body cannot be empty".to_string()));
+    }
+    if body.len() > config.max_comment_bytes {
         return Err(AppError::InvalidInput(
-            format!("Cannot sign proposal with status: {:?}", proposal.status)
+            format!("Comment is too long ({} bytes, max {})", body.len(), config.max_comment_bytes)
         ));
     }
     
-    // Verify signer is a participant
-    let is_participant: Option<i32> = sqlx::query_scalar(
-        "SELECT 1 FROM lock_participants WHERE lock_root_hash = ? AND pkh = ?"
+    // Check and insert in one write transaction so concurrent comments can't overshoot the cap
+    let mut tx = pool.write.begin_with("BEGIN IMMEDIATE").await?;
+    let (status, proposer_pkh): (String, Pkh) = sqlx::query_as(
+        "SELECT status, proposer_pkh FROM proposals WHERE id = ?"
     )
-    .bind(&proposal.lock_root_hash)
-    .bind(&req.signer_pkh)
-    .fetch_optional(&pool)
-    .await?;
+    .bind(&id)
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Proposal {} not found", id)))?;
+    let status: ProposalStatus = status.parse().map_err(AppError::Internal)?;
+    // A draft is invisible to everyone but its proposer, so it can't be commented on either
+    draft_access(&id, &status, &proposer_pkh, Some(&req.author_pkh), false)?;
     
-    if is_participant.is_none() {
+    // Only wallet participants may comment
+    if !is_proposal_participant(&mut *tx, &id, &req.author_pkh).await? {
         return Err(AppError::InvalidInput(
-            format!("PKH {} is not a participant of this wallet", req.signer_pkh)
+            format!("PKH {} is not a participant of this wallet", req.author_pkh)
         ));
     }
     
-    // Check if already signed
-    let already_signed: Option<String> = sqlx::query_scalar(
-        "SELECT signer_pkh FROM proposal_signatures WHERE proposal_id = ? AND signer_pkh = ?"
+    let comment_count: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM proposal_comments WHERE proposal_id = ?"
     )
-    .bind(&proposal.id)
-    .bind(&req.signer_pkh)
-    .fetch_optional(&pool)
+    .bind(&id)
+    .fetch_one(&mut *tx)
     .await?;
     
-    if already_signed.is_some() {
+    if comment_count >= config.max_comments_per_proposal {
         return Err(AppError::InvalidInput(
-            format!("PKH {} has already signed this proposal", req.signer_pkh)
+            format!("Proposal already has the maximum of {} comments", config.max_comments_per_proposal)
         ));
     }
     
-    // Record signature with the signed tx data
-    let now = chrono::Utc::now().to_rfc3339();
+    let comment_id = Uuid::new_v4().to_string();
+    let now = now_rfc3339();
     sqlx::query(
-        "INSERT INTO proposal_signatures (proposal_id, signer_pkh, signed_tx_json, signed_at) VALUES (?, ?, ?, ?)"
+        "INSERT INTO proposal_comments (id, proposal_id, author_pkh, body, created_at) VALUES (?, ?, ?, ?, ?)"
     )
-    .bind(&proposal.id)
-    .bind(&req.signer_pkh)
-    .bind(&req.signed_tx_json)
+    .bind(&comment_id)
+    .bind(&id)
+    .bind(&req.author_pkh)
+    .bind(body)
     .bind(&now)
-    .execute(&pool)
+    .execute(&mut *tx)
     .await?;
+    tx.commit().await?;
     
-    // Count signatures
-    let sig_count: i32 = sqlx::query_scalar(
-        "SELECT COUNT(*) FROM proposal_signatures WHERE proposal_id = ?"
-    )
-    .bind(&proposal.id)
-    .fetch_one(&pool)
-    .await?;
-    
-    let ready_to_broadcast = sig_count >= proposal.threshold;
-    
-    // Update status if ready
-    if ready_to_broadcast {
-        let status_str = serde_json::to_string(&ProposalStatus::Ready)
-            .unwrap_or_else(|_| "ready".to_string())
-            .trim_matches('"')
-            .to_string();
-        sqlx::query("UPDATE proposals SET status = ?, updated_at = ? WHERE id = ?")
-            .bind(&status_str)
-            .bind(&now)
-            .bind(&proposal.id)
-            .execute(&pool)
-            .await?;
-    } else {
-        sqlx::query("UPDATE proposals SET updated_at = ? WHERE id = ?")
-            .bind(&now)
-            .bind(&proposal.id)
-            .execute(&pool)
-            .await?;
-    }
-    
-    Ok(Json(SignProposalResponse {
-        success: true,
-        signatures_collected: sig_count,
-        ready_to_broadcast,
+    Ok(Json(CommentResponse {
+        id: comment_id,
+        author_pkh: req.author_pkh,
+        body: body.to_string(),
+        created_at: now,
     }))
 }
 
+#[utoipa::path(
+    post, path = "/{id}/broadcast", tag = "proposals",
+    params(("id" = String, Path, description = "Proposal ID")),
+    request_body = BroadcastProposalRequest,
+    responses(
+        (status = 200, description = "`{ success, history_id }`", body = Object),
+        (status = 404, body = ErrorBody),
+    )
+)]
 async fn mark_broadcast(
     State(pool): State<DbPool>,
+    State(events): State<Arc<dyn EventSink>>,
     Path(id): Path<String>,
-    Json(req): Json<BroadcastProposalRequest>,
+    JsonBody(req): JsonBody<BroadcastProposalRequest>,
 ) -> Result<Json<serde_json::Value>, AppError> {
+    // One IMMEDIATE transaction, so two broadcasts can't both pass the duplicate check
+    let mut tx = pool.write.begin_with("BEGIN IMMEDIATE").await?;
+    
     let proposal: Proposal = sqlx::query_as::<_, Proposal>(
         "SELECT * FROM proposals WHERE id = ?"
     )
     .bind(&id)
-    .fetch_optional(&pool)
+    .fetch_optional(&mut *tx)
     .await?
     .ok_or_else(|| AppError::NotFound(format!("Proposal {} not found", id)))?;
     
-    let now = chrono::Utc::now().to_rfc3339();
+    let now = now_rfc3339();
     
     // Use the final tx_id if provided (after signature merging), otherwise use original
+    if let Some(tx_id) = &req.final_tx_id
+        && !is_valid_tx_id(tx_id)
+    {
+        return Err(AppError::InvalidInput(
+            format!("final_tx_id is not a valid base58 transaction ID: {:?}", tx_id)
+        ));
+    }
     let final_tx_id = req.final_tx_id.as_ref().unwrap_or(&proposal.tx_id);
     
+    // Only a ready proposal went through publishing and signature collection
+    if proposal.status != ProposalStatus::Ready {
+        return Err(AppError::InvalidInput(
+            format!("Cannot broadcast proposal with status: {:?}", proposal.status)
+        ));
+    }
+    
+    // A colliding tx_id in history means the client merged signatures incorrectly
+    let existing_history: Option<String> = sqlx::query_scalar(
+        "SELECT id FROM transaction_history WHERE tx_id = ? LIMIT 1"
+    )
+    .bind(final_tx_id)
+    .fetch_optional(&mut *tx)
+    .await?;
+    
+    if let Some(history_id) = existing_history {
+        return Err(AppError::InvalidInput(
+            format!("Transaction {} is already recorded in history (ID: {})", final_tx_id, history_id)
+        ));
+    }
+    
     // Get signers
     let signers: Vec<String> = sqlx::query_scalar(
-        "SELECT signer_pkh FROM proposal_signatures WHERE proposal_id = ?"
+        "SELECT signer_pkh FROM proposal_signatures WHERE proposal_id = ? ORDER BY signer_pkh"
     )
     .bind(&proposal.id)
-    .fetch_all(&pool)
+    .fetch_all(&mut *tx)
     .await?;
     
     let signers_json = serde_json::to_string(&signers)
         .map_err(|e| AppError::InvalidInput(format!("Failed to serialize signers: {}", e)))?;
     
+    // Guarded so a concurrent status change (expiry, another broadcast) isn't overwritten
+    let moved = sqlx::query("UPDATE proposals SET status = ?, updated_at = ? WHERE id = ? AND status = ?")
+        .bind(ProposalStatus::Broadcast.as_str())
+        .bind(&now)
+        .bind(&proposal.id)
+        .bind(ProposalStatus::Ready.as_str())
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+    if moved == 0 {
+        return Err(AppError::InvalidInput(
+            format!("Proposal {} is no longer ready to broadcast", proposal.id)
+        ));
+    }
+    
     // Create history entry with the FINAL tx_id (after merging signatures)
     let history_id = Uuid::new_v4().to_string();
-    let tx_status_str = serde_json::to_string(&TransactionStatus::Broadcast)
-        .unwrap_or_else(|_| "broadcast".to_string())
-        .trim_matches('"')
-        .to_string();
     sqlx::query(
         "INSERT INTO transaction_history (id, tx_id, lock_root_hash, proposer_pkh, status,
          total_input_nicks, seeds_json, signers_json, created_at, broadcast_at)
@@ -534,26 +2841,27 @@ async fn mark_broadcast(
     .bind(final_tx_id)
     .bind(&proposal.lock_root_hash)
     .bind(&proposal.proposer_pkh)
-    .bind(&tx_status_str)
+    .bind(TransactionStatus::Broadcast.as_str())
     .bind(proposal.total_input_nicks)
     .bind(&proposal.seeds_json)
     .bind(&signers_json)
     .bind(&proposal.created_at)
     .bind(&now)
-    .execute(&pool)
+    .execute(&mut *tx)
     .await?;
+    tx.commit().await?;
     
-    // Update proposal status
-    let status_str = serde_json::to_string(&ProposalStatus::Broadcast)
-        .unwrap_or_else(|_| "broadcast".to_string())
-        .trim_matches('"')
-        .to_string();
-    sqlx::query("UPDATE proposals SET status = ?, updated_at = ? WHERE id = ?")
-        .bind(&status_str)
-        .bind(&now)
-        .bind(&proposal.id)
-        .execute(&pool)
-        .await?;
+    tracing::info!(
+        proposal_id = %proposal.id,
+        history_id = %history_id,
+        tx_id = %final_tx_id,
+        "Proposal broadcast"
+    );
+    events.emit(ProposalEvent::Broadcast {
+        proposal_id: proposal.id.clone(),
+        tx_id: final_tx_id.clone(),
+        history_id: history_id.clone(),
+    });
     
     Ok(Json(serde_json::json!({
         "success": true,
@@ -561,24 +2869,44 @@ async fn mark_broadcast(
     })))
 }
 
+const MAX_TX_ID_LEN: usize = 128;
+
+/// Transaction IDs are base58-encoded hashes
+fn is_valid_tx_id(tx_id: &str) -> bool {
+    is_base58(tx_id, MAX_TX_ID_LEN)
+}
+
 /// Direct spend for 1-of-n wallets - bypasses proposal flow, records directly to history
+#[utoipa::path(
+    post, path = "/direct", tag = "proposals",
+    request_body = DirectSpendRequest,
+    responses(
+        (status = 200, body = DirectSpendResponse),
+        (status = 400, body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    )
+)]
 async fn direct_spend(
     State(pool): State<DbPool>,
-    Json(req): Json<DirectSpendRequest>,
+    JsonBody(req): JsonBody<DirectSpendRequest>,
 ) -> Result<Json<DirectSpendResponse>, AppError> {
-    // Verify the lock exists
-    let lock_exists: Option<i32> = sqlx::query_scalar(
-        "SELECT 1 FROM locks WHERE lock_root_hash = ? LIMIT 1"
+    // Verify the lock exists and is 1-of-n; multi-signature wallets must use the proposal flow
+    let threshold: i32 = sqlx::query_scalar(
+        "SELECT threshold FROM locks WHERE lock_root_hash = ? LIMIT 1"
     )
     .bind(&req.lock_root_hash)
-    .fetch_optional(&pool)
-    .await?;
+    .fetch_optional(&pool.write)
+    .await?
+    .ok_or_else(|| AppError::NotFound(
+        format!("Wallet with lock_root_hash {} not found", req.lock_root_hash)
+    ))?;
     
-    if lock_exists.is_none() {
-        return Err(AppError::NotFound(
-            format!("Wallet with lock_root_hash {} not found", req.lock_root_hash)
+    if threshold != 1 {
+        return Err(AppError::InvalidInput(
+            format!("Direct spend requires a 1-of-n wallet (this wallet requires {} signatures)", threshold)
         ));
     }
+    check_allowed_recipients(&pool.write, &req.lock_root_hash, &req.seeds).await?;
     
     // Verify sender is a participant
     let is_participant: Option<i32> = sqlx::query_scalar(
@@ -586,7 +2914,7 @@ async fn direct_spend(
     )
     .bind(&req.lock_root_hash)
     .bind(&req.sender_pkh)
-    .fetch_optional(&pool)
+    .fetch_optional(&pool.write)
     .await?;
     
     if is_participant.is_none() {
@@ -595,7 +2923,7 @@ async fn direct_spend(
         ));
     }
     
-    let now = chrono::Utc::now().to_rfc3339();
+    let now = now_rfc3339();
     let history_id = Uuid::new_v4().to_string();
     
     let seeds_json = serde_json::to_string(&req.seeds)
@@ -603,11 +2931,6 @@ async fn direct_spend(
     let signers_json = serde_json::to_string(&vec![&req.sender_pkh])
         .map_err(|e| AppError::InvalidInput(format!("Failed to serialize signers: {}", e)))?;
     
-    let tx_status_str = serde_json::to_string(&TransactionStatus::Broadcast)
-        .unwrap_or_else(|_| "broadcast".to_string())
-        .trim_matches('"')
-        .to_string();
-    
     sqlx::query(
         "INSERT INTO transaction_history (id, tx_id, lock_root_hash, proposer_pkh, status,
          total_input_nicks, seeds_json, signers_json, created_at, broadcast_at)
@@ -617,73 +2940,613 @@ async fn direct_spend(
     .bind(&req.tx_id)
     .bind(&req.lock_root_hash)
     .bind(&req.sender_pkh)
-    .bind(&tx_status_str)
+    .bind(TransactionStatus::Broadcast.as_str())
     .bind(req.total_input_nicks)
     .bind(&seeds_json)
     .bind(&signers_json)
     .bind(&now)
     .bind(&now)
-    .execute(&pool)
+    .execute(&pool.write)
     .await?;
     
+    tracing::info!(
+        history_id = %history_id,
+        tx_id = %req.tx_id,
+        lock_root_hash = %req.lock_root_hash,
+        sender_pkh = %req.sender_pkh,
+        "Direct spend recorded"
+    );
+    
     Ok(Json(DirectSpendResponse {
         success: true,
         history_id,
     }))
 }
 
+/// Streamed row by row, so large pages don't buffer in memory. A database error mid-stream
+/// truncates the body instead of returning an error envelope
+#[utoipa::path(
+    get, path = "/history", tag = "proposals",
+    params(HistoryQuery),
+    responses((status = 200, body = Vec<TransactionHistoryResponse>), (status = 400, body = ErrorBody))
+)]
 async fn get_history(
     State(pool): State<DbPool>,
-    Query(params): Query<ListProposalsQuery>,
-) -> Result<Json<Vec<TransactionHistoryResponse>>, AppError> {
-    let history: Vec<TransactionHistory> = if let Some(pkh) = &params.pkh {
+    State(config): State<Arc<Config>>,
+    Query(params): Query<HistoryQuery>,
+) -> Result<Response, AppError> {
+    let page = Pagination::from_query(params.limit, params.offset, &config)?;
+    
+    // Unconfirmed rows (NULL confirmed_at) sort last; ties break on broadcast_at then id
+    let order_by = match params.sort.as_deref() {
+        None | Some("broadcast_at") => "h.broadcast_at DESC, h.id DESC",
+        Some("confirmed_at") => "h.confirmed_at IS NULL, h.confirmed_at DESC, h.broadcast_at DESC, h.id DESC",
+        Some(other) => {
+            return Err(AppError::InvalidInput(format!(
+                "Invalid sort: {} (expected broadcast_at or confirmed_at)", other
+            )));
+        }
+    };
+    
+    let mut query = String::from("SELECT DISTINCT h.* FROM transaction_history h");
+    let mut conditions = Vec::new();
+    let mut binds: Vec<String> = Vec::new();
+    if let Some(pkh) = &params.pkh {
         // Get history for wallets where this PKH is a participant
-        sqlx::query_as::<_, TransactionHistory>(
-            "SELECT DISTINCT h.* FROM transaction_history h
-             INNER JOIN lock_participants lp ON h.lock_root_hash = lp.lock_root_hash
-             WHERE lp.pkh = ?
-             ORDER BY h.broadcast_at DESC"
-        )
-        .bind(pkh)
-        .fetch_all(&pool)
-        .await?
+        query.push_str(" INNER JOIN lock_participants lp ON h.lock_root_hash = lp.lock_root_hash");
+        conditions.push("lp.pkh = ?");
+        binds.push(pkh.to_string());
     } else if let Some(lock_root_hash) = &params.lock_root_hash {
-        sqlx::query_as::<_, TransactionHistory>(
-            "SELECT * FROM transaction_history WHERE lock_root_hash = ? ORDER BY broadcast_at DESC"
-        )
-        .bind(lock_root_hash)
-        .fetch_all(&pool)
+        conditions.push("h.lock_root_hash = ?");
+        binds.push(lock_root_hash.to_string());
+    }
+    if let Some(status_str) = &params.status {
+        // History statuses differ from proposal ones (e.g. "pending" is not valid here)
+        let filter_status: TransactionStatus = status_str.parse()
+            .map_err(|_| AppError::InvalidInput(format!(
+                "Invalid status: {} (expected broadcast, confirmed or failed)", status_str
+            )))?;
+        conditions.push("h.status = ?");
+        binds.push(filter_status.as_str().to_string());
+    }
+    if !conditions.is_empty() {
+        query.push_str(" WHERE ");
+        query.push_str(&conditions.join(" AND "));
+    }
+    query.push_str(" ORDER BY ");
+    query.push_str(order_by);
+    query.push_str(" LIMIT ? OFFSET ?");
+    
+    // The task owns the query and its row stream; the body streams whatever it writes
+    let (mut writer, body) = json_array_body();
+    let read_pool = pool.read.clone();
+    let config = config.clone();
+    tokio::spawn(async move {
+        let mut history_query = sqlx::query_as::<_, TransactionHistory>(&query);
+        for value in &binds {
+            history_query = history_query.bind(value);
+        }
+        let mut rows = history_query
+            .bind(page.limit)
+            .bind(page.offset)
+            .fetch(&read_pool);
+        while let Some(row) = rows.next().await {
+            match row {
+                Ok(h) => {
+                    if !writer.push(&history_response(h, &config)).await {
+                        return;
+                    }
+                }
+                Err(e) => return writer.fail(e).await,
+            }
+        }
+        writer.finish().await;
+    });
+    
+    Ok(([(header::CONTENT_TYPE, "application/json")], body).into_response())
+}
+
+fn history_response(h: TransactionHistory, config: &Config) -> TransactionHistoryResponse {
+    let (seeds, seeds_corrupt) = decode_stored_json::<Vec<SeedSummary>>("transaction_history", &h.id, "seeds_json", &h.seeds_json);
+    let (signers, signers_corrupt) = decode_stored_json::<Vec<Pkh>>("transaction_history", &h.id, "signers_json", &h.signers_json);
+    
+    TransactionHistoryResponse {
+        id: h.id,
+        tx_id: h.tx_id,
+        lock_root_hash: h.lock_root_hash,
+        proposer_pkh: h.proposer_pkh,
+        status: h.status,
+        total_input_nicks: h.total_input_nicks,
+        total_input_display: config.format_nicks(h.total_input_nicks),
+        seeds: seed_responses(seeds, config),
+        seeds_corrupt,
+        signers,
+        signers_corrupt,
+        created_at: h.created_at,
+        broadcast_at: h.broadcast_at,
+        confirmed_at: h.confirmed_at,
+        block_height: h.block_height,
+        confirmations: h.confirmations,
+    }
+}
+
+
+/// Recovery for a transaction dropped from the mempool: re-announces the same tx_id
+/// through the event sink and bumps broadcast_at, without creating new history
+#[utoipa::path(
+    post, path = "/history/{id}/rebroadcast", tag = "proposals",
+    params(("id" = String, Path, description = "Transaction history ID")),
+    responses(
+        (status = 200, body = RebroadcastResponse),
+        (status = 400, description = "Transaction is already confirmed or failed", body = ErrorBody),
+        (status = 404, body = ErrorBody),
+    )
+)]
+async fn rebroadcast_history(
+    State(pool): State<DbPool>,
+    State(events): State<Arc<dyn EventSink>>,
+    Path(id): Path<String>,
+) -> Result<Json<RebroadcastResponse>, AppError> {
+    let history: TransactionHistory = sqlx::query_as::<_, TransactionHistory>(
+        "SELECT * FROM transaction_history WHERE id = ?"
+    )
+    .bind(&id)
+    .fetch_optional(&pool.write)
+    .await?
+    .ok_or_else(|| AppError::NotFound(format!("Transaction history {} not found", id)))?;
+    
+    if history.status != TransactionStatus::Broadcast {
+        return Err(AppError::InvalidInput(
+            format!("Cannot rebroadcast transaction with status: {:?}", history.status)
+        ));
+    }
+    
+    let now = now_rfc3339();
+    // Guarded so a confirmation or failure landing since the read isn't re-announced
+    let bumped = sqlx::query("UPDATE transaction_history SET broadcast_at = ? WHERE id = ? AND status = ?")
+        .bind(&now)
+        .bind(&history.id)
+        .bind(TransactionStatus::Broadcast.as_str())
+        .execute(&pool.write)
         .await?
-    } else {
-        sqlx::query_as::<_, TransactionHistory>(
-            "SELECT * FROM transaction_history ORDER BY broadcast_at DESC"
+        .rows_affected();
+    if bumped == 0 {
+        return Err(AppError::InvalidInput(
+            format!("Transaction {} is no longer broadcast", history.id)
+        ));
+    }
+    
+    tracing::info!(history_id = %history.id, tx_id = %history.tx_id, "Transaction rebroadcast");
+    events.emit(ProposalEvent::Rebroadcast {
+        history_id: history.id.clone(),
+        tx_id: history.tx_id.clone(),
+        lock_root_hash: history.lock_root_hash,
+    });
+    
+    Ok(Json(RebroadcastResponse {
+        history_id: history.id,
+        tx_id: history.tx_id,
+        broadcast_at: now,
+    }))
+}
+
+/// Reconciliation: marks many broadcast transactions confirmed in one DB transaction.
+/// Unknown tx_ids and rows that are no longer broadcast are reported back rather than
+/// failing the batch.
+#[utoipa::path(
+    post, path = "/history/confirm-batch", tag = "proposals",
+    request_body = Vec<ConfirmTransactionEntry>,
+    security(("admin_token" = [])),
+    responses(
+        (status = 200, body = ConfirmBatchResponse),
+        (status = 400, body = ErrorBody),
+        (status = 401, body = ErrorBody),
+    )
+)]
+async fn confirm_history_batch(
+    _admin: AdminAuth,
+    State(pool): State<DbPool>,
+    State(config): State<Arc<Config>>,
+    State(events): State<Arc<dyn EventSink>>,
+    JsonBody(mut entries): JsonBody<Vec<ConfirmTransactionEntry>>,
+) -> Result<Json<ConfirmBatchResponse>, AppError> {
+    // Validate the whole batch before touching the database; confirmed_at is stored in
+    // canonical UTC form so it orders correctly against server-written timestamps
+    let latest_allowed = rfc3339_secs_from_now(config.max_clock_skew_secs);
+    for entry in &mut entries {
+        entry.confirmed_at = canonicalize_rfc3339(&entry.confirmed_at)
+            .map_err(|e| AppError::InvalidInput(
+                format!("Invalid confirmed_at for tx {}: {}", entry.tx_id, e)
+            ))?;
+        if entry.confirmed_at > latest_allowed {
+            return Err(AppError::InvalidInput(format!(
+                "confirmed_at for tx {} is in the future: {}", entry.tx_id, entry.confirmed_at
+            )));
+        }
+    }
+    
+    let mut updated = Vec::new();
+    let mut not_found = Vec::new();
+    let mut not_updated = Vec::new();
+    let mut confirmed_events = Vec::new();
+    let mut tx = pool.write.begin_with("BEGIN IMMEDIATE").await?;
+    for entry in entries {
+        // Only broadcast rows move; a failed tx stays failed and a repeat confirmation
+        // doesn't reset block_height or depth
+        let rows = sqlx::query(
+            "UPDATE transaction_history SET status = ?, confirmed_at = ?, block_height = ?, confirmations = 1
+             WHERE tx_id = ? AND status = ?"
         )
-        .fetch_all(&pool)
+        .bind(TransactionStatus::Confirmed.as_str())
+        .bind(&entry.confirmed_at)
+        .bind(entry.block_height)
+        .bind(&entry.tx_id)
+        .bind(TransactionStatus::Broadcast.as_str())
+        .execute(&mut *tx)
         .await?
-    };
-    
-    let responses: Vec<TransactionHistoryResponse> = history.into_iter().map(|h| {
-        let seeds: Vec<SeedSummary> = serde_json::from_str(&h.seeds_json).unwrap_or_default();
-        let signers: Vec<String> = serde_json::from_str(&h.signers_json).unwrap_or_default();
+        .rows_affected();
         
-        TransactionHistoryResponse {
-            id: h.id,
-            tx_id: h.tx_id,
-            lock_root_hash: h.lock_root_hash,
-            proposer_pkh: h.proposer_pkh,
-            status: serde_json::to_string(&h.status)
-                .unwrap_or_default()
-                .trim_matches('"')
-                .to_string(),
-            total_input_nicks: h.total_input_nicks,
-            seeds,
-            signers,
-            created_at: h.created_at,
-            broadcast_at: h.broadcast_at,
-            confirmed_at: h.confirmed_at,
+        if rows > 0 {
+            confirmed_events.push(ProposalEvent::Confirmed {
+                tx_id: entry.tx_id.clone(),
+                block_height: entry.block_height,
+            });
+            updated.push(entry.tx_id);
+            continue;
         }
-    }).collect();
+        let exists: Option<i32> = sqlx::query_scalar("SELECT 1 FROM transaction_history WHERE tx_id = ?")
+            .bind(&entry.tx_id)
+            .fetch_optional(&mut *tx)
+            .await?;
+        if exists.is_some() {
+            not_updated.push(entry.tx_id);
+        } else {
+            not_found.push(entry.tx_id);
+        }
+    }
+    tx.commit().await?;
     
-    Ok(Json(responses))
+    for confirmed in &confirmed_events {
+        events.emit(confirmed.clone());
+    }
+    
+    Ok(Json(ConfirmBatchResponse { updated, not_found, not_updated }))
+}
+
+/// Refreshes confirmation depth of every confirmed transaction against the new chain tip.
+/// Admin-only: depth can't be lowered again, so a bogus tip would stick
+#[utoipa::path(
+    post, path = "/history/chain-tip", tag = "proposals",
+    request_body = ChainTipRequest,
+    security(("admin_token" = [])),
+    responses(
+        (status = 200, body = ChainTipResponse),
+        (status = 400, body = ErrorBody),
+        (status = 401, body = ErrorBody),
+    )
+)]
+async fn update_chain_tip(
+    _admin: AdminAuth,
+    State(pool): State<DbPool>,
+    JsonBody(req): JsonBody<ChainTipRequest>,
+) -> Result<Json<ChainTipResponse>, AppError> {
+    if req.block_height < 1 {
+        return Err(AppError::InvalidInput(
+            format!("block_height must be positive, got {}", req.block_height)
+        ));
+    }
+    
+    // Depth only ever grows; a stale tip never lowers it
+    let updated = sqlx::query(
+        "UPDATE transaction_history SET confirmations = ? - block_height + 1
+         WHERE block_height IS NOT NULL AND ? - block_height + 1 > confirmations"
+    )
+    .bind(req.block_height)
+    .bind(req.block_height)
+    .execute(&pool.write)
+    .await?
+    .rows_affected();
+    
+    Ok(Json(ChainTipResponse { updated }))
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use std::time::Duration;
+    use crate::db::{create_pool, MIGRATOR};
+
+    #[derive(Default)]
+    struct RecordingSink(Mutex<Vec<ProposalEvent>>);
+
+    impl EventSink for RecordingSink {
+        fn emit(&self, event: ProposalEvent) {
+            self.0.lock().unwrap().push(event);
+        }
+    }
+
+    impl RecordingSink {
+        fn ready_count(&self) -> usize {
+            self.0.lock().unwrap().iter().filter(|e| matches!(e, ProposalEvent::Ready { .. })).count()
+        }
+    }
+
+    /// File-backed database so concurrent signers really hold separate connections
+    async fn file_pool() -> (DbPool, std::path::PathBuf) {
+        crate::init_tracing();
+        let path = std::env::temp_dir().join(format!("agora-test-{}.db", Uuid::new_v4()));
+        let pool = create_pool(&format!("sqlite:{}", path.display()), Duration::from_secs(30), Config::default().statement_cache_capacity)
+            .await
+            .unwrap();
+        MIGRATOR.run(&pool).await.unwrap();
+        (DbPool { write: pool.clone(), read: pool }, path)
+    }
+
+    /// PKH of the `i`th test signer: Sa, Sb, ... (base58 has no 0, so no S0)
+    fn signer(i: usize) -> String {
+        format!("S{}", char::from(b'a' + i as u8))
+    }
+
+    /// Wallet with `signers` participants (Sa, Sb, ...) and one pending proposal P1
+    async fn seed_proposal(pool: &DbPool, signers: usize, threshold: i32) {
+        let now = now_rfc3339();
+        sqlx::query("INSERT INTO locks (lock_root_hash, threshold, total_signers, created_at, created_by_pkh) VALUES ('L1', ?, ?, ?, 'Sa')")
+            .bind(threshold)
+            .bind(signers as i32)
+            .bind(&now)
+            .execute(&pool.write)
+            .await
+            .unwrap();
+        for i in 0..signers {
+            sqlx::query("INSERT INTO lock_participants (lock_root_hash, pkh) VALUES ('L1', ?)")
+                .bind(signer(i))
+                .execute(&pool.write)
+                .await
+                .unwrap();
+        }
+        sqlx::query(
+            "INSERT INTO proposals (id, tx_id, lock_root_hash, proposer_pkh, status, threshold, raw_tx_json, notes_json,
+             spend_conditions_json, total_input_nicks, seeds_json, created_at, updated_at)
+             VALUES ('P1', 'T1', 'L1', 'Sa', 'pending', ?, '{}', '[]', '{}', 100, '[]', ?, ?)"
+        )
+        .bind(threshold)
+        .bind(&now)
+        .bind(&now)
+        .execute(&pool.write)
+        .await
+        .unwrap();
+    }
+
+    async fn sign(pool: &DbPool, events: &Arc<RecordingSink>, signer: String) -> Result<SignProposalResponse, AppError> {
+        let req = SignProposalRequest { signer_pkh: Pkh::parse(signer).unwrap(), signed_tx_json: "{}".to_string(), nonce: 1, on_behalf_of: None, signer_note: None };
+        let sink: Arc<dyn EventSink> = events.clone();
+        sign_proposal(State(pool.clone()), State(sink), Path("P1".to_string()), JsonBody(req))
+            .await
+            .map(|Json(resp)| resp)
+    }
+
+    /// Fires one sign request per signer at once and returns the results
+    async fn sign_concurrently(pool: &DbPool, events: &Arc<RecordingSink>, signers: impl Iterator<Item = usize>) -> Vec<Result<SignProposalResponse, AppError>> {
+        let tasks: Vec<_> = signers
+            .map(|i| {
+                let (pool, events) = (pool.clone(), events.clone());
+                tokio::spawn(async move { sign(&pool, &events, signer(i)).await })
+            })
+            .collect();
+        let mut results = Vec::new();
+        for task in tasks {
+            results.push(task.await.unwrap());
+        }
+        results
+    }
+
+    async fn proposal_state(pool: &DbPool) -> (String, i32) {
+        let status: String = sqlx::query_scalar("SELECT status FROM proposals WHERE id = 'P1'")
+            .fetch_one(&pool.read)
+            .await
+            .unwrap();
+        let count: i32 = sqlx::query_scalar("SELECT COUNT(*) FROM proposal_signatures WHERE proposal_id = 'P1'")
+            .fetch_one(&pool.read)
+            .await
+            .unwrap();
+        (status, count)
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn concurrent_signers_reaching_threshold_count_every_signature() {
+        let (pool, path) = file_pool().await;
+        seed_proposal(&pool, 8, 8).await;
+        let events = Arc::new(RecordingSink::default());
+
+        let results = sign_concurrently(&pool, &events, 0..8).await;
+
+        let mut counts: Vec<i32> = results.into_iter().map(|r| r.unwrap().signatures_collected).collect();
+        counts.sort();
+        assert_eq!(counts, (1..=8).collect::<Vec<_>>());
+        assert_eq!(proposal_state(&pool).await, ("ready".to_string(), 8));
+        assert_eq!(events.ready_count(), 1);
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn concurrent_signers_past_threshold_mark_ready_once() {
+        let (pool, path) = file_pool().await;
+        seed_proposal(&pool, 10, 3).await;
+        let events = Arc::new(RecordingSink::default());
+        for i in 0..2 {
+            sign(&pool, &events, signer(i)).await.unwrap();
+        }
+
+        let results = sign_concurrently(&pool, &events, 2..10).await;
+
+        let ready: Vec<_> = results.iter().filter_map(|r| r.as_ref().ok()).collect();
+        assert_eq!(ready.len(), 1);
+        assert!(ready[0].ready_to_broadcast);
+        assert_eq!(ready[0].signatures_collected, 3);
+        assert!(results.iter().filter(|r| r.is_err()).all(|r| matches!(r, Err(AppError::InvalidInput(_)))));
+        assert_eq!(proposal_state(&pool).await, ("ready".to_string(), 3));
+        assert_eq!(events.ready_count(), 1);
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[tokio::test]
+    async fn signing_a_removed_proposal_is_not_found_and_leaves_no_orphan() {
+        let (pool, path) = file_pool().await;
+        seed_proposal(&pool, 3, 2).await;
+        sqlx::query("DELETE FROM proposals WHERE id = 'P1'").execute(&pool.write).await.unwrap();
+        let events = Arc::new(RecordingSink::default());
+
+        assert!(matches!(sign(&pool, &events, signer(1)).await, Err(AppError::NotFound(_))));
+        // The schema rejects a signature row for a missing proposal outright
+        let orphan = sqlx::query(
+            "INSERT INTO proposal_signatures (proposal_id, signer_pkh, signed_tx_json, signed_at) VALUES ('P1', 'Sb', '{}', '')"
+        )
+        .execute(&pool.write)
+        .await;
+        assert!(orphan.is_err());
+        let (count,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM proposal_signatures")
+            .fetch_one(&pool.read)
+            .await
+            .unwrap();
+        assert_eq!(count, 0);
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[tokio::test]
+    async fn required_signer_holds_readiness_past_the_threshold() {
+        let (pool, path) = file_pool().await;
+        seed_proposal(&pool, 3, 2).await;
+        sqlx::query("UPDATE lock_participants SET required = 1 WHERE pkh = 'Sc'").execute(&pool.write).await.unwrap();
+        let events = Arc::new(RecordingSink::default());
+
+        sign(&pool, &events, signer(0)).await.unwrap();
+        let resp = sign(&pool, &events, signer(1)).await.unwrap();
+        assert!(!resp.ready_to_broadcast);
+        assert_eq!(proposal_state(&pool).await, ("pending".to_string(), 2));
+
+        let withdrawal = WithdrawSignatureRequest { signer_pkh: Pkh::parse(signer(1)).unwrap(), reason: None };
+        let sink: Arc<dyn EventSink> = events.clone();
+        let withdrawn = withdraw_signature(State(pool.clone()), State(sink), Path("P1".to_string()), JsonBody(withdrawal)).await;
+        assert!(matches!(withdrawn, Err(AppError::InvalidInput(_))));
+
+        let resp = sign(&pool, &events, signer(2)).await.unwrap();
+        assert!(resp.ready_to_broadcast);
+        assert_eq!(proposal_state(&pool).await, ("ready".to_string(), 3));
+        assert_eq!(events.ready_count(), 1);
+        let _ = std::fs::remove_file(path);
+    }
+    
+    #[tokio::test]
+    async fn threshold_is_frozen_once_a_co_signer_has_signed() {
+        let (pool, path) = file_pool().await;
+        seed_proposal(&pool, 3, 2).await;
+        let events = Arc::new(RecordingSink::default());
+        // The proposer's own signature is absent (e.g. withdrawn); a co-signer's is the only one
+        sign(&pool, &events, signer(1)).await.unwrap();
+
+        let req = UpdateProposalRequest { proposer_pkh: Pkh::parse(signer(0)).unwrap(), threshold: Some(1) };
+        let sink: Arc<dyn EventSink> = events.clone();
+        let updated = update_proposal(State(pool.clone()), State(sink), Path("P1".to_string()), JsonBody(req)).await;
+        assert!(matches!(updated, Err(AppError::InvalidInput(_))));
+        assert_eq!(proposal_state(&pool).await, ("pending".to_string(), 1));
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[tokio::test]
+    async fn allow_list_admits_change_to_the_wallet() {
+        let (pool, path) = file_pool().await;
+        seed_proposal(&pool, 3, 2).await;
+        sqlx::query("INSERT INTO lock_allowed_recipients (lock_root_hash, recipient, added_at) VALUES ('L1', 'wallet:Rx', ?)")
+            .bind(now_rfc3339())
+            .execute(&pool.write)
+            .await
+            .unwrap();
+        let request = |tx_id: &str, recipients: &[&str]| -> CreateProposalRequest {
+            let seeds: Vec<_> = recipients.iter().map(|r| serde_json::json!({"recipient": r, "amount_nicks": 10})).collect();
+            serde_json::from_value(serde_json::json!({
+                "tx_id": tx_id, "lock_root_hash": "L1", "proposer_pkh": "Sa", "threshold": 2,
+                "raw_tx_json": "{}", "notes_json": "[]", "spend_conditions_json": "[]",
+                "total_input_nicks": 100, "seeds": seeds, "proposer_signed_tx_json": "{}",
+            }))
+            .unwrap()
+        };
+        let mut conn = pool.write.acquire().await.unwrap();
+        let config = Config::default();
+
+        insert_proposal(&mut conn, &config, &request("T2", &["wallet:Rx", "lockroot:L1"])).await.unwrap();
+        insert_proposal(&mut conn, &config, &request("T3", &["wallet:Rx", "L1"])).await.unwrap();
+        match insert_proposal(&mut conn, &config, &request("T4", &["wallet:Ry", "lockroot:L1"])).await {
+            Err(AppError::InvalidInput(msg)) => assert!(msg.contains("wallet:Ry"), "{}", msg),
+            other => panic!("expected InvalidInput, got {:?}", other.map(|c| c.id)),
+        }
+        drop(conn);
+        let _ = std::fs::remove_file(path);
+    }
+
+    async fn comment(pool: &DbPool, config: &Arc<Config>, author: usize) -> Result<CommentResponse, AppError> {
+        let req = AddCommentRequest { author_pkh: Pkh::parse(signer(author)).unwrap(), body: "ok?".to_string() };
+        add_comment(State(pool.clone()), State(config.clone()), Path("P1".to_string()), JsonBody(req))
+            .await
+            .map(|Json(resp)| resp)
+    }
+
+    #[tokio::test]
+    async fn only_the_proposer_can_comment_on_a_draft() {
+        let (pool, path) = file_pool().await;
+        seed_proposal(&pool, 2, 2).await;
+        sqlx::query("UPDATE proposals SET status = 'draft' WHERE id = 'P1'").execute(&pool.write).await.unwrap();
+        let config = Arc::new(Config::default());
+
+        match comment(&pool, &config, 1).await {
+            Err(AppError::NotFound(msg)) => assert_eq!(msg, "Proposal P1 not found"),
+            other => panic!("expected NotFound, got {:?}", other.map(|c| c.id)),
+        }
+        comment(&pool, &config, 0).await.unwrap();
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn concurrent_comments_stop_at_the_cap() {
+        let (pool, path) = file_pool().await;
+        seed_proposal(&pool, 8, 2).await;
+        let mut config = Config::default();
+        config.max_comments_per_proposal = 3;
+        let config = Arc::new(config);
+
+        let tasks: Vec<_> = (0..8)
+            .map(|i| {
+                let (pool, config) = (pool.clone(), config.clone());
+                tokio::spawn(async move { comment(&pool, &config, i).await })
+            })
+            .collect();
+        let mut accepted = 0;
+        for task in tasks {
+            if task.await.unwrap().is_ok() {
+                accepted += 1;
+            }
+        }
+
+        assert_eq!(accepted, 3);
+        let stored: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM proposal_comments WHERE proposal_id = 'P1'")
+            .fetch_one(&pool.read)
+            .await
+            .unwrap();
+        assert_eq!(stored, 3);
+        let _ = std::fs::remove_file(path);
+    }
+
+    #[test]
+    fn deterministic_proposal_ids_depend_only_on_wallet_and_tx() {
+        let mut config = Config::default();
+        config.deterministic_proposal_ids = true;
+        let l1 = LockRootHash::parse("L1").unwrap();
+        let l2 = LockRootHash::parse("L2").unwrap();
+        let id = new_proposal_id(&config, &l1, "T1");
+        assert_eq!(id.get_version_num(), 5);
+        assert_eq!(id, new_proposal_id(&config, &l1, "T1"));
+        assert_ne!(id, new_proposal_id(&config, &l2, "T1"));
+        assert_ne!(id, new_proposal_id(&config, &l1, "T2"));
+        assert_eq!(new_proposal_id(&Config::default(), &l1, "T1").get_version_num(), 4);
+    }
+}
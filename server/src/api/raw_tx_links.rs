@@ -0,0 +1,49 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::error::AppError;
+
+/// What a link signs: the proposal and its expiry (unix seconds), scoped to raw-tx so the
+/// same secret can't be replayed for another purpose
+fn signed_message(proposal_id: &str, expires: i64) -> String {
+    format!("raw-tx:{}:{}", proposal_id, expires)
+}
+
+fn mac(secret: &str, proposal_id: &str, expires: i64) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(signed_message(proposal_id, expires).as_bytes());
+    mac
+}
+
+/// Hex HMAC-SHA256 for a raw-tx link to `proposal_id` valid until `expires`
+pub fn sign(secret: &str, proposal_id: &str, expires: i64) -> String {
+    hex::encode(mac(secret, proposal_id, expires).finalize().into_bytes())
+}
+
+/// Accepts a link for this proposal that hasn't expired at `now` (unix seconds); the
+/// signature comparison is constant-time
+pub fn verify(secret: &str, proposal_id: &str, expires: i64, sig: &str, now: i64) -> Result<(), AppError> {
+    if expires <= now {
+        return Err(AppError::Unauthorized("Signed link has expired".to_string()));
+    }
+    let sig = hex::decode(sig).map_err(|_| AppError::Unauthorized("Invalid link signature".to_string()))?;
+    mac(secret, proposal_id, expires)
+        .verify_slice(&sig)
+        .map_err(|_| AppError::Unauthorized("Invalid link signature".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_only_the_signed_proposal_before_expiry() {
+        let sig = sign("secret", "P1", 1_000);
+        assert!(verify("secret", "P1", 1_000, &sig, 999).is_ok());
+        assert!(verify("secret", "P1", 1_000, &sig, 1_000).is_err());
+        assert!(verify("secret", "P2", 1_000, &sig, 999).is_err());
+        assert!(verify("secret", "P1", 2_000, &sig, 999).is_err());
+        assert!(verify("other", "P1", 1_000, &sig, 999).is_err());
+        assert!(verify("secret", "P1", 1_000, "not hex", 999).is_err());
+    }
+}
@@ -0,0 +1,49 @@
+use std::fmt::Display;
+
+use axum::body::{Body, Bytes};
+use futures_util::stream;
+use serde::Serialize;
+use tokio::sync::mpsc;
+
+/// Chunks buffered ahead of a slow client before the producer waits
+const BUFFERED_CHUNKS: usize = 32;
+
+/// Writes a JSON array into a streaming response body one element at a time, so large
+/// listings are never held in memory whole. Fill it from a background task that owns the
+/// database stream; see [`json_array_body`]
+pub struct JsonArrayWriter {
+    tx: mpsc::Sender<Result<Bytes, std::io::Error>>,
+    empty: bool,
+}
+
+/// The writer to fill and the response body it streams into
+pub fn json_array_body() -> (JsonArrayWriter, Body) {
+    let (tx, rx) = mpsc::channel(BUFFERED_CHUNKS);
+    let chunks = stream::unfold(rx, |mut rx| async move { rx.recv().await.map(|chunk| (chunk, rx)) });
+    (JsonArrayWriter { tx, empty: true }, Body::from_stream(chunks))
+}
+
+impl JsonArrayWriter {
+    /// Appends one element. Returns false once the client has gone away, so the producer can stop
+    pub async fn push<T: Serialize>(&mut self, item: &T) -> bool {
+        let mut chunk = if self.empty { b"[".to_vec() } else { b",".to_vec() };
+        if let Err(e) = serde_json::to_writer(&mut chunk, item) {
+            self.fail(e).await;
+            return false;
+        }
+        self.empty = false;
+        self.tx.send(Ok(chunk.into())).await.is_ok()
+    }
+
+    /// Aborts the body. The 200 status is already sent, so the client sees a truncated
+    /// (invalid) JSON document rather than an error envelope
+    pub async fn fail(&self, error: impl Display) {
+        tracing::error!("Streaming response aborted: {}", error);
+        let _ = self.tx.send(Err(std::io::Error::other(error.to_string()))).await;
+    }
+
+    pub async fn finish(self) {
+        let closing: &'static [u8] = if self.empty { b"[]" } else { b"]" };
+        let _ = self.tx.send(Ok(Bytes::from_static(closing))).await;
+    }
+}
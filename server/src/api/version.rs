@@ -0,0 +1,33 @@
+use axum::{routing::get, Json, Router};
+use serde::Serialize;
+use utoipa::{OpenApi, ToSchema};
+use crate::api::AppState;
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct VersionResponse {
+    pub version: String,
+    pub git_commit: String, // "unknown" when built outside a git checkout without GIT_COMMIT
+    pub built_at: String,   // RFC3339, UTC
+}
+
+#[derive(OpenApi)]
+#[openapi(paths(get_version))]
+pub struct VersionApi;
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(get_version))
+}
+
+/// Build info of the running gateway, for correlating bug reports with deployments
+#[utoipa::path(
+    get, path = "", tag = "version",
+    responses((status = 200, body = VersionResponse))
+)]
+async fn get_version() -> Json<VersionResponse> {
+    Json(VersionResponse {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        git_commit: env!("AGORA_GIT_COMMIT").to_string(),
+        built_at: env!("AGORA_BUILD_TIMESTAMP").to_string(),
+    })
+}
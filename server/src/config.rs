@@ -1,29 +1,566 @@
 use std::env;
+use std::fs;
+use std::net::{IpAddr, SocketAddr};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
 
+use axum::http::{HeaderName, HeaderValue, Method};
+use serde::Deserialize;
+use thiserror::Error;
+
+const DEFAULT_CONFIG_PATH: &str = "config.toml";
+/// A day; anything larger is a misconfiguration rather than a clock tolerance
+const MAX_CLOCK_SKEW_LIMIT_SECS: u64 = 86_400;
+/// Keeps the scaled amount well inside the i128 used for formatting
+const MAX_DENOMINATION_DECIMALS: u32 = 18;
+/// A year of proposals is as far back as the velocity baseline usefully reaches
+const MAX_ANOMALY_BASELINE_DAYS: u32 = 365;
+/// Signed links are meant for an immediate hand-off, not as long-lived credentials
+const MAX_RAW_TX_LINK_TTL_SECS: u64 = 86_400;
+
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("Failed to read config file {path}: {message}")]
+    File { path: String, message: String },
+
+    #[error("{var} is not a valid {expected}: {value:?}")]
+    InvalidValue {
+        var: &'static str,
+        value: String,
+        expected: &'static str,
+    },
+
+    #[error("API_HOST is not a valid bind address: {0:?} (expected an IP or unix:/path/to/socket)")]
+    InvalidBindAddress(String),
+
+    #[error("CORS_ORIGIN is not a valid origin: {0:?} (expected scheme://host[:port])")]
+    InvalidCorsOrigin(String),
+
+    #[error("{var} must be greater than zero")]
+    MustBePositive { var: &'static str },
+
+    #[error("EVENT_SINK must be one of {expected}: {value:?}")]
+    InvalidEventSink { value: String, expected: &'static str },
+
+    #[error("{var} is required when {reason}")]
+    Missing { var: &'static str, reason: &'static str },
+}
+
+/// Settings are read from an optional TOML file (`AGORA_CONFIG`, default `config.toml`),
+/// then overridden by any matching env vars
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct Config {
     pub database_url: String,
+    pub read_database_url: Option<String>,
+    pub api_host: String,
     pub api_port: u16,
     pub cors_origin: String,
+    pub cors_allowed_methods: Vec<String>,
+    pub cors_allowed_headers: Vec<String>,
+    pub cors_allow_credentials: bool, // Lets browsers send cookies/Authorization cross-origin
+    pub db_acquire_timeout_secs: u64,
+    pub statement_cache_capacity: usize, // Prepared statements kept per connection; 0 disables reuse
+    pub migrations_verify_only: bool, // Report the migration state and exit without applying anything or serving
+    pub max_notes_json_bytes: usize,
+    pub max_spend_conditions_json_bytes: usize,
+    pub max_signers: usize,
+    pub max_lookup_pkhs: usize,
+    pub max_metadata_json_bytes: usize,
+    pub max_comment_bytes: usize,
+    pub max_comments_per_proposal: i64,
+    pub default_page_size: i64,
+    pub max_page_size: i64,
+    pub history_retention_days: Option<u64>, // Archival job is disabled unless set
+    pub archival_interval_secs: u64,
+    pub expiry_sweep_interval_secs: u64, // How often overdue pending proposals are marked expired
+    pub max_clock_skew_secs: u64, // Tolerance for client-supplied timestamps vs the server clock
+    pub strict_json: bool, // Reject request bodies with fields the endpoint doesn't declare, instead of ignoring them
+    pub deterministic_proposal_ids: bool, // Derive proposal ids from lock_root_hash + tx_id (UUIDv5) instead of random v4
+    pub denomination_nicks_per_unit: u64, // Display amounts are nicks / this, e.g. 65536 per NOCK
+    pub denomination_decimals: u32, // Digits shown after the point (rounded)
+    pub denomination_symbol: String,
+    pub anomaly_baseline_days: u32, // Days before the last 24h averaged into a wallet's normal proposal rate
+    pub anomaly_velocity_multiple: f64, // A wallet is anomalous when its last-24h proposals exceed this multiple of that rate
+    pub proposal_write_concurrency: usize, // In-flight mutating requests under /api/proposals and /api/batch
+    pub multisig_write_concurrency: usize, // In-flight mutating requests under /api/multisigs
+    pub write_queue_timeout_ms: u64, // How long an excess write waits for a slot before a 503
+    pub read_only: bool, // Maintenance mode: reject mutating requests with 503 (admins can toggle at runtime)
+    pub compression: bool, // gzip/brotli response compression, negotiated via Accept-Encoding
+    pub event_sink: String, // "log", "webhook" or (with the `nats` feature) "nats"
+    pub event_webhook_url: Option<String>,
+    pub webhook_allow_private_targets: bool, // Let wallet webhooks point at loopback/private/link-local hosts (e.g. a local relay)
+    pub nats_url: Option<String>,
+    pub nats_subject: String,
+    pub admin_token: Option<String>, // Bearer token for admin endpoints; they are disabled when unset
+    pub raw_tx_link_secret: Option<String>, // HMAC key for signed raw-tx links; when set, raw-tx needs a link or the admin token
+    pub raw_tx_link_ttl_secs: u64, // How long a minted raw-tx link stays valid
+    pub audit_log: bool, // Record every mutating request in audit_log (GET /api/admin/audit)
+    pub trust_forwarded_for: bool, // Take the audited client IP from X-Forwarded-For; only behind a trusted proxy
+    // Load/parse failures, reported together by validate()
+    #[serde(skip)]
+    errors: Vec<ConfigError>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            database_url: "sqlite:./data.db".to_string(),
+            read_database_url: None,
+            api_host: "0.0.0.0".to_string(),
+            api_port: 3000,
+            cors_origin: "http://localhost:5173".to_string(),
+            cors_allowed_methods: ["GET", "POST", "PUT", "PATCH", "DELETE", "OPTIONS"]
+                .map(String::from)
+                .to_vec(),
+            cors_allowed_headers: ["Content-Type", "Authorization", "Idempotency-Key", "X-Request-Id", "X-Json-Case"]
+                .map(String::from)
+                .to_vec(),
+            cors_allow_credentials: false,
+            db_acquire_timeout_secs: 10,
+            statement_cache_capacity: 100,
+            migrations_verify_only: false,
+            max_notes_json_bytes: 256 * 1024,
+            max_spend_conditions_json_bytes: 64 * 1024,
+            max_signers: 32,
+            max_lookup_pkhs: 200,
+            max_metadata_json_bytes: 16 * 1024,
+            max_comment_bytes: 4 * 1024,
+            max_comments_per_proposal: 500,
+            default_page_size: 100,
+            max_page_size: 500,
+            history_retention_days: None,
+            archival_interval_secs: 3600,
+            expiry_sweep_interval_secs: 60,
+            max_clock_skew_secs: 300,
+            strict_json: false,
+            deterministic_proposal_ids: false,
+            denomination_nicks_per_unit: 65536,
+            denomination_decimals: 4,
+            denomination_symbol: "NOCK".to_string(),
+            anomaly_baseline_days: 7,
+            anomaly_velocity_multiple: 3.0,
+            proposal_write_concurrency: 64,
+            multisig_write_concurrency: 64,
+            write_queue_timeout_ms: 5000,
+            read_only: false,
+            compression: true,
+            event_sink: "log".to_string(),
+            event_webhook_url: None,
+            webhook_allow_private_targets: false,
+            nats_url: None,
+            nats_subject: "agora.proposals".to_string(),
+            admin_token: None,
+            raw_tx_link_secret: None,
+            raw_tx_link_ttl_secs: 300,
+            audit_log: true,
+            trust_forwarded_for: false,
+            errors: Vec::new(),
+        }
+    }
 }
 
 impl Config {
-    pub fn from_env() -> Self {
-        let database_url = env::var("DATABASE_URL")
-            .unwrap_or_else(|_| "sqlite:./data.db".to_string());
-        
-        let api_port = env::var("API_PORT")
-            .unwrap_or_else(|_| "3000".to_string())
-            .parse()
-            .unwrap_or(3000);
-        
-        let cors_origin = env::var("CORS_ORIGIN")
-            .unwrap_or_else(|_| "http://localhost:5173".to_string());
+    pub fn load() -> Self {
+        let path = env::var("AGORA_CONFIG").unwrap_or_else(|_| DEFAULT_CONFIG_PATH.to_string());
+        let mut config = Self::from_file(&path);
+        config.apply_env();
+        config
+    }
 
-        Self {
-            database_url,
-            api_port,
-            cors_origin,
+    /// Missing file yields the defaults; an unreadable or malformed one is recorded as an error
+    fn from_file(path: &str) -> Self {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Self::default(),
+            Err(e) => return Self::with_error(path, e.to_string()),
+        };
+        toml::from_str(&contents).unwrap_or_else(|e| Self::with_error(path, e.to_string()))
+    }
+
+    fn with_error(path: &str, message: String) -> Self {
+        let mut config = Self::default();
+        config.errors.push(ConfigError::File { path: path.to_string(), message });
+        config
+    }
+
+    fn apply_env(&mut self) {
+        let errors = &mut self.errors;
+        override_env(&mut self.database_url, "DATABASE_URL", "string", errors);
+        override_env_optional(&mut self.read_database_url, "READ_DATABASE_URL", "string", errors);
+        override_env(&mut self.api_host, "API_HOST", "string", errors);
+        override_env(&mut self.api_port, "API_PORT", "port", errors);
+        override_env(&mut self.cors_origin, "CORS_ORIGIN", "string", errors);
+        override_env_list(&mut self.cors_allowed_methods, "CORS_ALLOWED_METHODS");
+        override_env_list(&mut self.cors_allowed_headers, "CORS_ALLOWED_HEADERS");
+        override_env(&mut self.cors_allow_credentials, "CORS_ALLOW_CREDENTIALS", "boolean", errors);
+        override_env(&mut self.db_acquire_timeout_secs, "DB_ACQUIRE_TIMEOUT_SECS", "number of seconds", errors);
+        override_env(&mut self.statement_cache_capacity, "STATEMENT_CACHE_CAPACITY", "number", errors);
+        override_env(&mut self.migrations_verify_only, "MIGRATIONS_VERIFY_ONLY", "boolean", errors);
+        override_env(&mut self.max_notes_json_bytes, "MAX_NOTES_JSON_BYTES", "byte count", errors);
+        override_env(&mut self.max_spend_conditions_json_bytes, "MAX_SPEND_CONDITIONS_JSON_BYTES", "byte count", errors);
+        override_env(&mut self.max_signers, "MAX_SIGNERS", "number", errors);
+        override_env(&mut self.max_lookup_pkhs, "MAX_LOOKUP_PKHS", "number", errors);
+        override_env(&mut self.max_metadata_json_bytes, "MAX_METADATA_JSON_BYTES", "byte count", errors);
+        override_env(&mut self.max_comment_bytes, "MAX_COMMENT_BYTES", "byte count", errors);
+        override_env(&mut self.max_comments_per_proposal, "MAX_COMMENTS_PER_PROPOSAL", "number", errors);
+        override_env(&mut self.default_page_size, "DEFAULT_PAGE_SIZE", "number", errors);
+        override_env(&mut self.max_page_size, "MAX_PAGE_SIZE", "number", errors);
+        override_env_optional(&mut self.history_retention_days, "HISTORY_RETENTION_DAYS", "number of days", errors);
+        override_env(&mut self.archival_interval_secs, "ARCHIVAL_INTERVAL_SECS", "number of seconds", errors);
+        override_env(&mut self.expiry_sweep_interval_secs, "EXPIRY_SWEEP_INTERVAL_SECS", "number of seconds", errors);
+        override_env(&mut self.max_clock_skew_secs, "MAX_CLOCK_SKEW_SECS", "number of seconds", errors);
+        override_env(&mut self.strict_json, "STRICT_JSON", "boolean", errors);
+        override_env(&mut self.deterministic_proposal_ids, "DETERMINISTIC_PROPOSAL_IDS", "boolean", errors);
+        override_env(&mut self.denomination_nicks_per_unit, "DENOMINATION_NICKS_PER_UNIT", "number", errors);
+        override_env(&mut self.denomination_decimals, "DENOMINATION_DECIMALS", "number", errors);
+        override_env(&mut self.denomination_symbol, "DENOMINATION_SYMBOL", "string", errors);
+        override_env(&mut self.anomaly_baseline_days, "ANOMALY_BASELINE_DAYS", "number of days", errors);
+        override_env(&mut self.anomaly_velocity_multiple, "ANOMALY_VELOCITY_MULTIPLE", "number", errors);
+        override_env(&mut self.proposal_write_concurrency, "PROPOSAL_WRITE_CONCURRENCY", "number", errors);
+        override_env(&mut self.multisig_write_concurrency, "MULTISIG_WRITE_CONCURRENCY", "number", errors);
+        override_env(&mut self.write_queue_timeout_ms, "WRITE_QUEUE_TIMEOUT_MS", "number of milliseconds", errors);
+        override_env(&mut self.read_only, "READ_ONLY", "boolean", errors);
+        override_env(&mut self.compression, "COMPRESSION", "boolean", errors);
+        override_env(&mut self.event_sink, "EVENT_SINK", "string", errors);
+        override_env_optional(&mut self.event_webhook_url, "EVENT_WEBHOOK_URL", "string", errors);
+        override_env(&mut self.webhook_allow_private_targets, "WEBHOOK_ALLOW_PRIVATE_TARGETS", "boolean", errors);
+        override_env_optional(&mut self.nats_url, "NATS_URL", "string", errors);
+        override_env(&mut self.nats_subject, "NATS_SUBJECT", "string", errors);
+        override_env_optional(&mut self.admin_token, "ADMIN_TOKEN", "string", errors);
+        override_env_optional(&mut self.raw_tx_link_secret, "RAW_TX_LINK_SECRET", "string", errors);
+        override_env(&mut self.raw_tx_link_ttl_secs, "RAW_TX_LINK_TTL_SECS", "number of seconds", errors);
+        override_env(&mut self.audit_log, "AUDIT_LOG", "boolean", errors);
+        override_env(&mut self.trust_forwarded_for, "TRUST_FORWARDED_FOR", "boolean", errors);
+    }
+
+    /// Checks every setting and returns all problems at once rather than stopping at the first
+    pub fn validate(&mut self) -> Result<(), Vec<ConfigError>> {
+        let mut errors = std::mem::take(&mut self.errors);
+
+        if let Err(e) = self.bind_target() {
+            errors.push(e);
+        }
+        if let Err(e) = self.cors_allow_origin() {
+            errors.push(e);
+        }
+        if let Err(e) = self.cors_allow_methods() {
+            errors.push(e);
+        }
+        if let Err(e) = self.cors_allow_headers() {
+            errors.push(e);
+        }
+        if self.db_acquire_timeout_secs == 0 {
+            errors.push(ConfigError::MustBePositive { var: "DB_ACQUIRE_TIMEOUT_SECS" });
+        }
+        if self.max_notes_json_bytes == 0 {
+            errors.push(ConfigError::MustBePositive { var: "MAX_NOTES_JSON_BYTES" });
+        }
+        if self.max_spend_conditions_json_bytes == 0 {
+            errors.push(ConfigError::MustBePositive { var: "MAX_SPEND_CONDITIONS_JSON_BYTES" });
+        }
+        if self.max_signers == 0 {
+            errors.push(ConfigError::MustBePositive { var: "MAX_SIGNERS" });
+        }
+        if self.max_lookup_pkhs == 0 {
+            errors.push(ConfigError::MustBePositive { var: "MAX_LOOKUP_PKHS" });
+        }
+        if self.max_metadata_json_bytes == 0 {
+            errors.push(ConfigError::MustBePositive { var: "MAX_METADATA_JSON_BYTES" });
+        }
+        if self.max_comment_bytes == 0 {
+            errors.push(ConfigError::MustBePositive { var: "MAX_COMMENT_BYTES" });
+        }
+        if self.max_comments_per_proposal <= 0 {
+            errors.push(ConfigError::MustBePositive { var: "MAX_COMMENTS_PER_PROPOSAL" });
+        }
+        if self.default_page_size <= 0 {
+            errors.push(ConfigError::MustBePositive { var: "DEFAULT_PAGE_SIZE" });
+        }
+        if self.max_page_size <= 0 {
+            errors.push(ConfigError::MustBePositive { var: "MAX_PAGE_SIZE" });
+        }
+        if self.default_page_size > self.max_page_size {
+            errors.push(ConfigError::InvalidValue {
+                var: "DEFAULT_PAGE_SIZE",
+                value: self.default_page_size.to_string(),
+                expected: "page size no greater than MAX_PAGE_SIZE",
+            });
+        }
+        if self.history_retention_days == Some(0) {
+            errors.push(ConfigError::MustBePositive { var: "HISTORY_RETENTION_DAYS" });
+        }
+        if self.archival_interval_secs == 0 {
+            errors.push(ConfigError::MustBePositive { var: "ARCHIVAL_INTERVAL_SECS" });
+        }
+        if self.expiry_sweep_interval_secs == 0 {
+            errors.push(ConfigError::MustBePositive { var: "EXPIRY_SWEEP_INTERVAL_SECS" });
+        }
+        if self.max_clock_skew_secs > MAX_CLOCK_SKEW_LIMIT_SECS {
+            errors.push(ConfigError::InvalidValue {
+                var: "MAX_CLOCK_SKEW_SECS",
+                value: self.max_clock_skew_secs.to_string(),
+                expected: "number of seconds up to 86400",
+            });
+        }
+        if self.denomination_nicks_per_unit == 0 {
+            errors.push(ConfigError::MustBePositive { var: "DENOMINATION_NICKS_PER_UNIT" });
+        }
+        if self.denomination_decimals > MAX_DENOMINATION_DECIMALS {
+            errors.push(ConfigError::InvalidValue {
+                var: "DENOMINATION_DECIMALS",
+                value: self.denomination_decimals.to_string(),
+                expected: "number of decimals between 0 and 18",
+            });
+        }
+        if self.anomaly_baseline_days == 0 || self.anomaly_baseline_days > MAX_ANOMALY_BASELINE_DAYS {
+            errors.push(ConfigError::InvalidValue {
+                var: "ANOMALY_BASELINE_DAYS",
+                value: self.anomaly_baseline_days.to_string(),
+                expected: "number of days between 1 and 365",
+            });
+        }
+        if !(self.anomaly_velocity_multiple.is_finite() && self.anomaly_velocity_multiple > 0.0) {
+            errors.push(ConfigError::InvalidValue {
+                var: "ANOMALY_VELOCITY_MULTIPLE",
+                value: self.anomaly_velocity_multiple.to_string(),
+                expected: "positive number",
+            });
+        }
+        if self.proposal_write_concurrency == 0 {
+            errors.push(ConfigError::MustBePositive { var: "PROPOSAL_WRITE_CONCURRENCY" });
+        }
+        if self.multisig_write_concurrency == 0 {
+            errors.push(ConfigError::MustBePositive { var: "MULTISIG_WRITE_CONCURRENCY" });
         }
+        if self.admin_token.as_deref().is_some_and(|t| t.trim().is_empty()) {
+            errors.push(ConfigError::InvalidValue {
+                var: "ADMIN_TOKEN",
+                value: String::new(),
+                expected: "non-empty token",
+            });
+        }
+        if self.raw_tx_link_secret.as_deref().is_some_and(|s| s.trim().is_empty()) {
+            errors.push(ConfigError::InvalidValue {
+                var: "RAW_TX_LINK_SECRET",
+                value: String::new(),
+                expected: "non-empty secret",
+            });
+        }
+        if self.raw_tx_link_ttl_secs == 0 || self.raw_tx_link_ttl_secs > MAX_RAW_TX_LINK_TTL_SECS {
+            errors.push(ConfigError::InvalidValue {
+                var: "RAW_TX_LINK_TTL_SECS",
+                value: self.raw_tx_link_ttl_secs.to_string(),
+                expected: "number of seconds between 1 and 86400",
+            });
+        }
+        match self.event_sink.as_str() {
+            "log" => {}
+            "webhook" if self.event_webhook_url.is_none() => {
+                errors.push(ConfigError::Missing { var: "EVENT_WEBHOOK_URL", reason: "EVENT_SINK=webhook" });
+            }
+            "webhook" => {}
+            #[cfg(feature = "nats")]
+            "nats" if self.nats_url.is_none() => {
+                errors.push(ConfigError::Missing { var: "NATS_URL", reason: "EVENT_SINK=nats" });
+            }
+            #[cfg(feature = "nats")]
+            "nats" => {}
+            other => errors.push(ConfigError::InvalidEventSink {
+                value: other.to_string(),
+                expected: if cfg!(feature = "nats") { "log, webhook, nats" } else { "log, webhook" },
+            }),
+        }
+
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+
+    /// `API_HOST=unix:/path/to/socket` listens on a Unix socket (API_PORT is then unused);
+    /// anything else must be an IP address
+    pub fn bind_target(&self) -> Result<BindTarget, ConfigError> {
+        if let Some(path) = self.api_host.strip_prefix("unix:") {
+            if path.is_empty() {
+                return Err(ConfigError::InvalidBindAddress(self.api_host.clone()));
+            }
+            return Ok(BindTarget::Unix(PathBuf::from(path)));
+        }
+        let ip = IpAddr::from_str(&self.api_host)
+            .map_err(|_| ConfigError::InvalidBindAddress(self.api_host.clone()))?;
+        Ok(BindTarget::Tcp(SocketAddr::new(ip, self.api_port)))
+    }
+
+    pub fn cors_allow_origin(&self) -> Result<HeaderValue, ConfigError> {
+        let invalid = || ConfigError::InvalidCorsOrigin(self.cors_origin.clone());
+        let host = self.cors_origin
+            .strip_prefix("http://")
+            .or_else(|| self.cors_origin.strip_prefix("https://"))
+            .ok_or_else(invalid)?;
+        if host.is_empty() || host.contains('/') {
+            return Err(invalid());
+        }
+        HeaderValue::from_str(&self.cors_origin).map_err(|_| invalid())
+    }
+
+    pub fn cors_allow_methods(&self) -> Result<Vec<Method>, ConfigError> {
+        self.cors_allowed_methods
+            .iter()
+            .map(|m| {
+                Method::from_str(&m.to_ascii_uppercase()).map_err(|_| ConfigError::InvalidValue {
+                    var: "CORS_ALLOWED_METHODS",
+                    value: m.clone(),
+                    expected: "HTTP method",
+                })
+            })
+            .collect()
+    }
+
+    pub fn cors_allow_headers(&self) -> Result<Vec<HeaderName>, ConfigError> {
+        self.cors_allowed_headers
+            .iter()
+            .map(|h| {
+                HeaderName::from_str(h).map_err(|_| ConfigError::InvalidValue {
+                    var: "CORS_ALLOWED_HEADERS",
+                    value: h.clone(),
+                    expected: "header name",
+                })
+            })
+            .collect()
+    }
+
+    pub fn db_acquire_timeout(&self) -> Duration {
+        Duration::from_secs(self.db_acquire_timeout_secs)
+    }
+
+    /// Renders a raw nicks amount in the configured denomination, e.g. "1.5000 NOCK",
+    /// rounding half up at the last shown digit
+    pub fn format_nicks(&self, nicks: i64) -> String {
+        let decimals = self.denomination_decimals;
+        let per_unit = i128::from(self.denomination_nicks_per_unit);
+        let scale = 10i128.pow(decimals);
+        let scaled = (i128::from(nicks).abs() * scale + per_unit / 2) / per_unit;
+        let sign = if nicks < 0 && scaled > 0 { "-" } else { "" };
+        let amount = if decimals == 0 {
+            scaled.to_string()
+        } else {
+            format!("{}.{:0width$}", scaled / scale, scaled % scale, width = decimals as usize)
+        };
+        if self.denomination_symbol.is_empty() {
+            format!("{}{}", sign, amount)
+        } else {
+            format!("{}{} {}", sign, amount, self.denomination_symbol)
+        }
+    }
+
+    pub fn write_queue_timeout(&self) -> Duration {
+        Duration::from_millis(self.write_queue_timeout_ms)
     }
 }
 
+/// The variable's value, treating blank as unset so `.env.example`'s `VAR=` lines keep the defaults
+fn env_value(var: &str) -> Option<String> {
+    env::var(var).ok().filter(|value| !value.trim().is_empty())
+}
+
+/// Overrides a setting from an env var if set, recording a ConfigError on parse failure
+fn override_env<T: FromStr>(
+    target: &mut T,
+    var: &'static str,
+    expected: &'static str,
+    errors: &mut Vec<ConfigError>,
+) {
+    if let Some(value) = env_value(var) {
+        match value.trim().parse() {
+            Ok(parsed) => *target = parsed,
+            Err(_) => errors.push(ConfigError::InvalidValue { var, value, expected }),
+        }
+    }
+}
+
+/// Comma-separated list; surrounding whitespace and empty entries are ignored, and a blank
+/// value leaves the default
+fn override_env_list(target: &mut Vec<String>, var: &'static str) {
+    if let Some(value) = env_value(var) {
+        *target = value
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(String::from)
+            .collect();
+    }
+}
+
+fn override_env_optional<T: FromStr>(
+    target: &mut Option<T>,
+    var: &'static str,
+    expected: &'static str,
+    errors: &mut Vec<ConfigError>,
+) {
+    if let Some(value) = env_value(var) {
+        match value.trim().parse() {
+            Ok(parsed) => *target = Some(parsed),
+            Err(_) => errors.push(ConfigError::InvalidValue { var, value, expected }),
+        }
+    }
+}
+
+/// Where the server listens
+#[derive(Debug, PartialEq)]
+pub enum BindTarget {
+    Tcp(SocketAddr),
+    Unix(PathBuf),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bind_target_accepts_an_ip_or_a_unix_socket_path() {
+        let mut config = Config::default();
+        assert_eq!(config.bind_target().unwrap(), BindTarget::Tcp("0.0.0.0:3000".parse().unwrap()));
+        config.api_host = "unix:/run/agora/api.sock".to_string();
+        assert_eq!(config.bind_target().unwrap(), BindTarget::Unix(PathBuf::from("/run/agora/api.sock")));
+        config.api_host = "unix:".to_string();
+        assert!(config.bind_target().is_err());
+        config.api_host = "localhost".to_string();
+        assert!(config.bind_target().is_err());
+    }
+
+    #[test]
+    fn blank_env_values_keep_the_defaults() {
+        // SAFETY: no other test reads or writes these variables
+        unsafe {
+            env::set_var("AGORA_TEST_BLANK_PORT", "  ");
+            env::set_var("AGORA_TEST_BLANK_TOKEN", "");
+        }
+        let mut port: u16 = 3000;
+        let mut token: Option<String> = None;
+        let mut errors = Vec::new();
+        override_env(&mut port, "AGORA_TEST_BLANK_PORT", "port number", &mut errors);
+        override_env_optional(&mut token, "AGORA_TEST_BLANK_TOKEN", "string", &mut errors);
+        assert_eq!(port, 3000);
+        assert_eq!(token, None);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn format_nicks_uses_the_configured_denomination() {
+        let mut config = Config::default();
+        assert_eq!(config.format_nicks(65536), "1.0000 NOCK");
+        assert_eq!(config.format_nicks(98304), "1.5000 NOCK");
+        assert_eq!(config.format_nicks(1), "0.0000 NOCK");
+        assert_eq!(config.format_nicks(4), "0.0001 NOCK"); // 0.000061 rounds up
+        assert_eq!(config.format_nicks(-98304), "-1.5000 NOCK");
+        assert_eq!(config.format_nicks(-1), "0.0000 NOCK");
+
+        config.denomination_nicks_per_unit = 1000;
+        config.denomination_decimals = 3;
+        assert_eq!(config.format_nicks(i64::MIN), "-9223372036854775.808 NOCK");
+
+        config.denomination_nicks_per_unit = 1;
+        config.denomination_decimals = 0;
+        config.denomination_symbol = String::new();
+        assert_eq!(config.format_nicks(1500), "1500");
+    }
+}
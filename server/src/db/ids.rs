@@ -0,0 +1,124 @@
+use std::fmt;
+use std::ops::Deref;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::error::AppError;
+use crate::validation::{is_base58, MAX_PKH_LEN};
+
+/// A base58 identifier that can only be built through `parse`, so a lock-root hash and a PKH
+/// can't be swapped for one another (or for any other string) without the compiler noticing.
+/// Values read back from the database are trusted as stored.
+macro_rules! base58_id {
+    ($(#[$doc:meta])* $name:ident, $what:literal) => {
+        $(#[$doc])*
+        #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, ToSchema, sqlx::Type)]
+        #[serde(try_from = "String", into = "String")]
+        #[sqlx(transparent)]
+        pub struct $name(String);
+
+        impl $name {
+            pub fn parse(value: impl Into<String>) -> Result<Self, AppError> {
+                let value = value.into();
+                if is_base58(&value, MAX_PKH_LEN) {
+                    Ok(Self(value))
+                } else {
+                    Err(AppError::InvalidInput(format!(concat!("Invalid ", $what, ": {:?}"), value)))
+                }
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            pub fn into_inner(self) -> String {
+                self.0
+            }
+        }
+
+        impl Deref for $name {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        // Same as the bare string, so logged events and errors read as before
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.0, f)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = AppError;
+
+            fn try_from(value: String) -> Result<Self, AppError> {
+                Self::parse(value)
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> String {
+                id.0
+            }
+        }
+    };
+}
+
+base58_id!(
+    /// Base58 lock-root hash (the firstName) identifying a multisig wallet
+    LockRootHash, "lock_root_hash"
+);
+
+base58_id!(
+    /// Base58 public-key hash of a participant, proposer, delegate or recipient
+    Pkh, "pkh"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_accepts_only_base58() {
+        assert_eq!(Pkh::parse("AbC123").unwrap().as_str(), "AbC123");
+        assert!(Pkh::parse("").is_err());
+        assert!(Pkh::parse("0OIl").is_err());
+        assert!(LockRootHash::parse("x".repeat(MAX_PKH_LEN + 1)).is_err());
+    }
+
+    #[test]
+    fn serde_goes_through_parse() {
+        let lock: LockRootHash = serde_json::from_str("\"L1\"").unwrap();
+        assert_eq!(lock, "L1");
+        assert_eq!(serde_json::to_string(&lock).unwrap(), "\"L1\"");
+        assert!(serde_json::from_str::<Pkh>("\"not base58!\"").is_err());
+    }
+}
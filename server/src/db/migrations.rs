@@ -0,0 +1,172 @@
+use sqlx::migrate::{MigrateError, Migrator};
+use sqlx::{Pool, Sqlite};
+
+/// How the database's applied migrations compare with the ones built into this binary
+#[derive(Debug, Default, PartialEq)]
+pub struct MigrationReport {
+    pub applied: usize,
+    pub pending: Vec<i64>,  // Built in but not applied yet
+    pub dirty: Vec<i64>,    // Recorded as started but not successfully finished
+    pub modified: Vec<i64>, // Applied, but the file has changed since (checksum mismatch)
+    pub unknown: Vec<i64>,  // Applied by a build that has migrations this one doesn't
+}
+
+impl MigrationReport {
+    /// Nothing to apply and nothing inconsistent
+    pub fn is_current(&self) -> bool {
+        self.pending.is_empty() && self.dirty.is_empty() && self.modified.is_empty() && self.unknown.is_empty()
+    }
+}
+
+/// Migrations edited after release without changing the schema they produce, with the SHA-384
+/// checksum of the original file. A database that applied the original is still current
+const REVISED_MIGRATIONS: &[(i64, &str)] = &[
+    // 020: its index became CREATE INDEX IF NOT EXISTS
+    (20, "b635e8cf89787fcdb05e137b03b833cc11f5a999ff616e658d19a4be13cab1625a02971942c79941ecfaf831f7f6f3c5"),
+];
+
+fn is_original_of_revised(version: i64, checksum: &[u8]) -> bool {
+    REVISED_MIGRATIONS.iter().any(|(revised, original)| *revised == version && hex::encode(checksum) == *original)
+}
+
+/// Records the current checksum for revised migrations a database applied in their original
+/// form, so `Migrator::run` doesn't refuse them as modified. Run before migrating
+pub async fn adopt_revised_migrations(pool: &Pool<Sqlite>, migrator: &Migrator) -> Result<(), sqlx::Error> {
+    let has_table: Option<i32> = sqlx::query_scalar(
+        "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = '_sqlx_migrations'"
+    )
+    .fetch_optional(pool)
+    .await?;
+    if has_table.is_none() {
+        return Ok(());
+    }
+    for (version, original) in REVISED_MIGRATIONS {
+        let Some(migration) = migrator.iter().find(|m| m.version == *version) else { continue };
+        sqlx::query("UPDATE _sqlx_migrations SET checksum = ? WHERE version = ? AND hex(checksum) = upper(?)")
+            .bind(&*migration.checksum)
+            .bind(version)
+            .bind(original)
+            .execute(pool)
+            .await?;
+    }
+    Ok(())
+}
+
+/// Compares `_sqlx_migrations` with `migrator` without writing anything; a database that was
+/// never migrated simply has every migration pending
+pub async fn verify_migrations(pool: &Pool<Sqlite>, migrator: &Migrator) -> Result<MigrationReport, sqlx::Error> {
+    let has_table: Option<i32> = sqlx::query_scalar(
+        "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = '_sqlx_migrations'"
+    )
+    .fetch_optional(pool)
+    .await?;
+    let applied: Vec<(i64, Vec<u8>, bool)> = if has_table.is_some() {
+        sqlx::query_as("SELECT version, checksum, success FROM _sqlx_migrations ORDER BY version")
+            .fetch_all(pool)
+            .await?
+    } else {
+        Vec::new()
+    };
+    
+    let mut report = MigrationReport { applied: applied.len(), ..MigrationReport::default() };
+    for (version, checksum, success) in &applied {
+        match migrator.iter().find(|m| m.version == *version) {
+            None => report.unknown.push(*version),
+            Some(_) if !success => report.dirty.push(*version),
+            Some(m) if *m.checksum != **checksum && !is_original_of_revised(*version, checksum) => {
+                report.modified.push(*version)
+            }
+            Some(_) => {}
+        }
+    }
+    report.pending = migrator.iter()
+        .filter(|m| !m.migration_type.is_down_migration())
+        .map(|m| m.version)
+        .filter(|version| !applied.iter().any(|(applied, _, _)| applied == version))
+        .collect();
+    Ok(report)
+}
+
+/// Operator-facing explanation of a failed migration run, naming the migration involved
+pub fn describe_migration_error(migrator: &Migrator, error: &MigrateError) -> String {
+    let name = |version: i64| match migrator.iter().find(|m| m.version == version) {
+        Some(m) => format!("{} ({})", version, m.description),
+        None => version.to_string(),
+    };
+    match error {
+        MigrateError::ExecuteMigration(e, version) => format!(
+            "Migration {} failed: {}. SQLite runs each migration in a transaction, so it was rolled back; \
+             fix the cause (e.g. free up disk space) and restart to retry",
+            name(*version), e
+        ),
+        MigrateError::Dirty(version) => format!(
+            "Migration {} is recorded as partially applied. Inspect the schema, then delete its row from \
+             _sqlx_migrations and restart; MIGRATIONS_VERIFY_ONLY=true lists the state without changing it",
+            name(*version)
+        ),
+        MigrateError::VersionMismatch(version) => format!(
+            "Migration {} was changed after it was applied to this database; restore the original file",
+            name(*version)
+        ),
+        MigrateError::VersionMissing(version) => format!(
+            "Migration {} is applied to this database but not part of this build; \
+             the database was migrated by a newer version",
+            version
+        ),
+        other => format!("Database migration failed: {}", other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::{create_memory_pool, MIGRATOR};
+
+    #[tokio::test]
+    async fn verify_reports_pending_dirty_modified_and_unknown_versions() {
+        let pool = create_memory_pool().await.unwrap().write;
+        let report = verify_migrations(&pool, &MIGRATOR).await.unwrap();
+        assert!(report.is_current());
+        assert_eq!(report.applied, MIGRATOR.iter().count());
+
+        let last = MIGRATOR.iter().map(|m| m.version).max().unwrap();
+        for sql in [
+            "UPDATE _sqlx_migrations SET checksum = x'00' WHERE version = 1",
+            "UPDATE _sqlx_migrations SET success = 0 WHERE version = 2",
+            "INSERT INTO _sqlx_migrations (version, description, success, checksum, execution_time) VALUES (9999, 'future', 1, x'00', 0)",
+        ] {
+            sqlx::query(sql).execute(&pool).await.unwrap();
+        }
+        sqlx::query("DELETE FROM _sqlx_migrations WHERE version = ?").bind(last).execute(&pool).await.unwrap();
+
+        let report = verify_migrations(&pool, &MIGRATOR).await.unwrap();
+        assert_eq!(report.pending, vec![last]);
+        assert_eq!(report.dirty, vec![2]);
+        assert_eq!(report.modified, vec![1]);
+        assert_eq!(report.unknown, vec![9999]);
+        assert!(!report.is_current());
+    }
+
+    #[tokio::test]
+    async fn databases_with_an_original_revised_migration_stay_current() {
+        let pool = create_memory_pool().await.unwrap().write;
+        let (version, original) = REVISED_MIGRATIONS[0];
+        sqlx::query("UPDATE _sqlx_migrations SET checksum = ? WHERE version = ?")
+            .bind(hex::decode(original).unwrap())
+            .bind(version)
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert!(verify_migrations(&pool, &MIGRATOR).await.unwrap().is_current());
+
+        adopt_revised_migrations(&pool, &MIGRATOR).await.unwrap();
+        MIGRATOR.run(&pool).await.unwrap();
+        let stored: Vec<u8> = sqlx::query_scalar("SELECT checksum FROM _sqlx_migrations WHERE version = ?")
+            .bind(version)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let current = MIGRATOR.iter().find(|m| m.version == version).unwrap();
+        assert_eq!(stored, *current.checksum);
+    }
+}
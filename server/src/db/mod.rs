@@ -1,6 +1,12 @@
+pub mod ids;
+pub mod migrations;
 pub mod models;
 pub mod pool;
+pub mod time;
 
+pub use ids::*;
+pub use migrations::*;
 pub use models::*;
 pub use pool::*;
+pub use time::*;
 
@@ -1,10 +1,14 @@
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use utoipa::ToSchema;
+
+use super::ids::{LockRootHash, Pkh};
 
 // Proposal status 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum ProposalStatus {
+    Draft, // Visible only to the proposer until published
     Pending,
     Ready,
     Broadcast,
@@ -12,8 +16,18 @@ pub enum ProposalStatus {
     Expired,
 }
 
+// What a proposal is for, so clients can group and filter them
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ProposalType {
+    Payment,
+    Transfer,
+    #[default]
+    Other,
+}
+
 // Transaction history status
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum TransactionStatus {
     Broadcast,
@@ -22,30 +36,46 @@ pub enum TransactionStatus {
 }
 
 // Database models 
-#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
 pub struct Lock {
     #[sqlx(rename = "lock_root_hash")]
-    pub lock_root_hash: String, // Base58-encoded lock-root hash (firstName) - PRIMARY KEY
+    pub lock_root_hash: LockRootHash, // Base58-encoded lock-root hash (firstName) - PRIMARY KEY
     pub threshold: i32,
     pub total_signers: i32,
     #[sqlx(rename = "created_at")]
     pub created_at: String, // Stored as TEXT (RFC3339) in SQLite
-    pub created_by_pkh: String,
+    pub created_by_pkh: Pkh,
+    pub metadata_json: Option<String>, // Free-form JSON set by participants
+    pub signing_window_secs: Option<i64>, // Caps new proposals' expiry to created_at + window
 }
 
+// Lock joined with its most recent proposal/history activity (falls back to created_at)
+// and the value tied up in its unbroadcast proposals
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct LockWithActivity {
+    #[sqlx(flatten)]
+    pub lock: Lock,
+    pub last_activity_at: String,
+    pub pending_value_nicks: i64,
+    pub proposals_last_24h: i64,
+    pub proposals_baseline: i64, // Created in the anomaly baseline window before the last 24h
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
 pub struct LockParticipant {
-    pub lock_root_hash: String,
-    pub pkh: String,
+    pub lock_root_hash: LockRootHash,
+    pub pkh: Pkh,
+    pub active: bool, // Advisory: false while the co-signer is unavailable
+    pub required: bool, // Every proposal needs this participant's signature
 }
 
 // Transaction proposal awaiting signatures
-#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
 pub struct Proposal {
     pub id: String,
     pub tx_id: String,
-    pub lock_root_hash: String,
-    pub proposer_pkh: String,
+    pub lock_root_hash: LockRootHash,
+    pub proposer_pkh: Pkh,
     #[sqlx(try_from = "String")]
     pub status: ProposalStatus,
     pub threshold: i32,
@@ -56,6 +86,23 @@ pub struct Proposal {
     pub seeds_json: String,
     pub created_at: String,
     pub updated_at: String,
+    pub expires_at: Option<String>,
+    #[sqlx(try_from = "String")]
+    pub proposal_type: ProposalType,
+}
+
+impl ProposalStatus {
+    /// Stored form, identical to the serde rename
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProposalStatus::Draft => "draft",
+            ProposalStatus::Pending => "pending",
+            ProposalStatus::Ready => "ready",
+            ProposalStatus::Broadcast => "broadcast",
+            ProposalStatus::Confirmed => "confirmed",
+            ProposalStatus::Expired => "expired",
+        }
+    }
 }
 
 impl TryFrom<String> for ProposalStatus {
@@ -63,6 +110,7 @@ impl TryFrom<String> for ProposalStatus {
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
         match value.as_str() {
+            "draft" => Ok(ProposalStatus::Draft),
             "pending" => Ok(ProposalStatus::Pending),
             "ready" => Ok(ProposalStatus::Ready),
             "broadcast" => Ok(ProposalStatus::Broadcast),
@@ -78,6 +126,7 @@ impl std::str::FromStr for ProposalStatus {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
+            "draft" => Ok(ProposalStatus::Draft),
             "pending" => Ok(ProposalStatus::Pending),
             "ready" => Ok(ProposalStatus::Ready),
             "broadcast" => Ok(ProposalStatus::Broadcast),
@@ -88,22 +137,78 @@ impl std::str::FromStr for ProposalStatus {
     }
 }
 
+impl ProposalType {
+    /// Stored form, identical to the serde rename
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProposalType::Payment => "payment",
+            ProposalType::Transfer => "transfer",
+            ProposalType::Other => "other",
+        }
+    }
+}
+
+impl TryFrom<String> for ProposalType {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl std::str::FromStr for ProposalType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "payment" => Ok(ProposalType::Payment),
+            "transfer" => Ok(ProposalType::Transfer),
+            "other" => Ok(ProposalType::Other),
+            _ => Err(format!("Invalid proposal type: {} (expected payment, transfer or other)", s)),
+        }
+    }
+}
+
 // Signature record for a proposal (includes the actual signed tx data)
-#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
 pub struct ProposalSignature {
     pub proposal_id: String,
-    pub signer_pkh: String,
+    pub signer_pkh: Pkh,
     pub signed_tx_json: String, // The signed RawTx from this signer
     pub signed_at: String,
+    pub delegate_pkh: Option<Pkh>, // Set when signed on signer_pkh's behalf by this delegate
+    pub signer_note: Option<String>, // Where an out-of-band signature came from, as told by the submitter
+}
+
+// Temporary hand-over of a participant's signing authority within one wallet
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct Delegation {
+    pub id: String,
+    pub lock_root_hash: LockRootHash,
+    pub from_pkh: Pkh,
+    pub to_pkh: Pkh,
+    pub expires_at: String,
+    pub created_at: String,
+    pub revoked_at: Option<String>,
+}
+
+// Comment in a proposal's discussion thread
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct ProposalComment {
+    pub id: String,
+    pub proposal_id: String,
+    pub author_pkh: Pkh,
+    pub body: String,
+    pub created_at: String,
 }
 
 // Completed transaction history
-#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
 pub struct TransactionHistory {
     pub id: String,
     pub tx_id: String,
-    pub lock_root_hash: String,
-    pub proposer_pkh: String,
+    pub lock_root_hash: LockRootHash,
+    pub proposer_pkh: Pkh,
     #[sqlx(try_from = "String")]
     pub status: TransactionStatus,
     pub total_input_nicks: i64,
@@ -112,6 +217,19 @@ pub struct TransactionHistory {
     pub created_at: String,
     pub broadcast_at: Option<String>,
     pub confirmed_at: Option<String>,
+    pub block_height: Option<i64>,
+    pub confirmations: i64,
+}
+
+impl TransactionStatus {
+    /// Stored form, identical to the serde rename
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TransactionStatus::Broadcast => "broadcast",
+            TransactionStatus::Confirmed => "confirmed",
+            TransactionStatus::Failed => "failed",
+        }
+    }
 }
 
 impl TryFrom<String> for TransactionStatus {
@@ -127,3 +245,376 @@ impl TryFrom<String> for TransactionStatus {
     }
 }
 
+impl std::str::FromStr for TransactionStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "broadcast" => Ok(TransactionStatus::Broadcast),
+            "confirmed" => Ok(TransactionStatus::Confirmed),
+            "failed" => Ok(TransactionStatus::Failed),
+            _ => Err(format!("Invalid transaction status: {}", s)),
+        }
+    }
+}
+
+
+// Row of the combined participant feed (proposals UNION transaction_history)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct FeedItemRow {
+    pub item_type: String,
+    pub id: String,
+    pub tx_id: String,
+    pub lock_root_hash: LockRootHash,
+    pub proposer_pkh: Pkh,
+    pub status: String,
+    pub total_input_nicks: i64,
+    pub seeds_json: String,
+    pub timestamp: String,
+}
+
+// Per-participant signing activity for one wallet (zero counts for participants who never signed)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct SignerStatRow {
+    pub pkh: Pkh,
+    pub signatures: i64,
+    pub last_signed_at: Option<String>,
+}
+
+// Payments to one recipient aggregated over a wallet's live and archived history seeds
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct RecipientTotalRow {
+    pub recipient: String,
+    pub payments: i64,
+    pub total_paid_nicks: i64,
+    pub last_paid_at: String,
+}
+
+// A participant the queried wallet shares with another wallet, with that wallet's basics
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct SharedParticipantRow {
+    pub lock_root_hash: LockRootHash,
+    pub threshold: i32,
+    pub total_signers: i32,
+    pub created_at: String,
+    pub metadata_json: Option<String>,
+    pub pkh: Pkh,
+}
+
+// One mutating request in the security audit log
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct AuditLogEntry {
+    pub id: String,
+    pub created_at: String,
+    pub method: String,
+    pub path: String,
+    pub status: i64,
+    pub actor_pkh: Option<Pkh>,
+    pub admin: bool,
+    pub source_ip: Option<String>,
+    pub resource_id: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::{create_memory_pool, now_rfc3339, DbPool};
+
+    const RAW_TX_JSON: &str = r#"{"version":1,"spends":[{"name":{"first":"9yPe","last":"4Dq1"},"seeds":[{"recipient":"R1","gift":50,"parent_hash":"AbC"}],"fee":5}]}"#;
+    const NOTES_JSON: &str = r#"[{"name":{"first":"9yPe","last":"4Dq1"},"assets":100,"memo":"paiement été ✓"}]"#;
+    const SPEND_CONDITIONS_JSON: &str = r#"[{"pkh":{"m":2,"hashes":["A","B","C"]}}]"#;
+
+    async fn insert_lock(pool: &DbPool) -> Lock {
+        let lock = Lock {
+            lock_root_hash: LockRootHash::parse("L1").unwrap(),
+            threshold: 2,
+            total_signers: 3,
+            created_at: now_rfc3339(),
+            created_by_pkh: Pkh::parse("A").unwrap(),
+            metadata_json: Some(r#"{"name":"Treasury","tags":["cold"]}"#.to_string()),
+            signing_window_secs: Some(86_400),
+        };
+        sqlx::query(
+            "INSERT INTO locks (lock_root_hash, threshold, total_signers, created_at, created_by_pkh, metadata_json, signing_window_secs)
+             VALUES (?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(&lock.lock_root_hash)
+        .bind(lock.threshold)
+        .bind(lock.total_signers)
+        .bind(&lock.created_at)
+        .bind(&lock.created_by_pkh)
+        .bind(&lock.metadata_json)
+        .bind(lock.signing_window_secs)
+        .execute(&pool.write)
+        .await
+        .unwrap();
+        lock
+    }
+
+    fn proposal(id: &str, status: ProposalStatus) -> Proposal {
+        let now = now_rfc3339();
+        Proposal {
+            id: id.to_string(),
+            tx_id: format!("tx-{}", id),
+            lock_root_hash: LockRootHash::parse("L1").unwrap(),
+            proposer_pkh: Pkh::parse("A").unwrap(),
+            status,
+            threshold: 2,
+            raw_tx_json: RAW_TX_JSON.to_string(),
+            notes_json: NOTES_JSON.to_string(),
+            spend_conditions_json: SPEND_CONDITIONS_JSON.to_string(),
+            total_input_nicks: 100,
+            seeds_json: r#"[{"recipient":"R1","amount_nicks":50}]"#.to_string(),
+            created_at: now.clone(),
+            updated_at: now,
+            expires_at: Some("2099-01-01T00:00:00.000000Z".to_string()),
+            proposal_type: ProposalType::Payment,
+        }
+    }
+
+    async fn insert_proposal(pool: &DbPool, p: &Proposal) {
+        sqlx::query(
+            "INSERT INTO proposals (id, tx_id, lock_root_hash, proposer_pkh, status, threshold,
+             raw_tx_json, notes_json, spend_conditions_json, total_input_nicks, seeds_json,
+             created_at, updated_at, expires_at, proposal_type)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(&p.id)
+        .bind(&p.tx_id)
+        .bind(&p.lock_root_hash)
+        .bind(&p.proposer_pkh)
+        .bind(p.status.as_str())
+        .bind(p.threshold)
+        .bind(&p.raw_tx_json)
+        .bind(&p.notes_json)
+        .bind(&p.spend_conditions_json)
+        .bind(p.total_input_nicks)
+        .bind(&p.seeds_json)
+        .bind(&p.created_at)
+        .bind(&p.updated_at)
+        .bind(&p.expires_at)
+        .bind(p.proposal_type.as_str())
+        .execute(&pool.write)
+        .await
+        .unwrap();
+    }
+
+    #[test]
+    fn status_strings_match_serde_and_parse_back() {
+        for status in [ProposalStatus::Draft, ProposalStatus::Pending, ProposalStatus::Ready, ProposalStatus::Broadcast, ProposalStatus::Confirmed, ProposalStatus::Expired] {
+            assert_eq!(serde_json::to_value(&status).unwrap(), status.as_str());
+            assert_eq!(ProposalStatus::try_from(status.as_str().to_string()), Ok(status.clone()));
+            assert_eq!(status.as_str().parse::<ProposalStatus>(), Ok(status));
+        }
+        for status in [TransactionStatus::Broadcast, TransactionStatus::Confirmed, TransactionStatus::Failed] {
+            assert_eq!(serde_json::to_value(&status).unwrap(), status.as_str());
+            assert_eq!(TransactionStatus::try_from(status.as_str().to_string()), Ok(status.clone()));
+            assert_eq!(status.as_str().parse::<TransactionStatus>(), Ok(status));
+        }
+        for proposal_type in [ProposalType::Payment, ProposalType::Transfer, ProposalType::Other] {
+            assert_eq!(serde_json::to_value(&proposal_type).unwrap(), proposal_type.as_str());
+            assert_eq!(ProposalType::try_from(proposal_type.as_str().to_string()), Ok(proposal_type.clone()));
+        }
+    }
+
+    #[tokio::test]
+    async fn lock_and_participants_round_trip() {
+        let pool = create_memory_pool().await.unwrap();
+        let lock = insert_lock(&pool).await;
+        sqlx::query("INSERT INTO lock_participants (lock_root_hash, pkh, active) VALUES ('L1', 'A', 1), ('L1', 'B', 0)")
+            .execute(&pool.write)
+            .await
+            .unwrap();
+        
+        let stored: Lock = sqlx::query_as("SELECT * FROM locks WHERE lock_root_hash = 'L1'")
+            .fetch_one(&pool.read)
+            .await
+            .unwrap();
+        assert_eq!(stored, lock);
+        
+        let with_activity: LockWithActivity = sqlx::query_as(
+            "SELECT *, created_at AS last_activity_at, 0 AS pending_value_nicks, 0 AS proposals_last_24h, 0 AS proposals_baseline FROM locks"
+        )
+        .fetch_one(&pool.read)
+        .await
+        .unwrap();
+        assert_eq!(with_activity.lock, lock);
+        assert_eq!(with_activity.last_activity_at, lock.created_at);
+        assert_eq!(with_activity.pending_value_nicks, 0);
+        
+        let participants: Vec<LockParticipant> = sqlx::query_as("SELECT * FROM lock_participants ORDER BY pkh")
+            .fetch_all(&pool.read)
+            .await
+            .unwrap();
+        assert_eq!(participants, vec![
+            LockParticipant { lock_root_hash: LockRootHash::parse("L1").unwrap(), pkh: Pkh::parse("A").unwrap(), active: true, required: false },
+            LockParticipant { lock_root_hash: LockRootHash::parse("L1").unwrap(), pkh: Pkh::parse("B").unwrap(), active: false, required: false },
+        ]);
+    }
+
+    #[tokio::test]
+    async fn proposal_round_trips_json_blobs_and_every_status() {
+        let pool = create_memory_pool().await.unwrap();
+        insert_lock(&pool).await;
+        
+        let statuses = [ProposalStatus::Draft, ProposalStatus::Pending, ProposalStatus::Ready, ProposalStatus::Broadcast, ProposalStatus::Confirmed, ProposalStatus::Expired];
+        for (i, status) in statuses.into_iter().enumerate() {
+            let written = proposal(&format!("P{}", i), status);
+            insert_proposal(&pool, &written).await;
+            let read: Proposal = sqlx::query_as("SELECT * FROM proposals WHERE id = ?")
+                .bind(&written.id)
+                .fetch_one(&pool.read)
+                .await
+                .unwrap();
+            assert_eq!(read, written);
+        }
+    }
+
+    #[tokio::test]
+    async fn unknown_stored_status_fails_to_decode() {
+        let pool = create_memory_pool().await.unwrap();
+        insert_lock(&pool).await;
+        insert_proposal(&pool, &proposal("P1", ProposalStatus::Pending)).await;
+        sqlx::query("UPDATE proposals SET status = 'archived' WHERE id = 'P1'")
+            .execute(&pool.write)
+            .await
+            .unwrap();
+        
+        let result = sqlx::query_as::<_, Proposal>("SELECT * FROM proposals WHERE id = 'P1'")
+            .fetch_one(&pool.read)
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn proposal_children_round_trip() {
+        let pool = create_memory_pool().await.unwrap();
+        insert_lock(&pool).await;
+        insert_proposal(&pool, &proposal("P1", ProposalStatus::Pending)).await;
+        let now = now_rfc3339();
+        
+        let signature = ProposalSignature {
+            proposal_id: "P1".to_string(),
+            signer_pkh: Pkh::parse("B").unwrap(),
+            signed_tx_json: RAW_TX_JSON.to_string(),
+            signed_at: now.clone(),
+            delegate_pkh: Some(Pkh::parse("C").unwrap()),
+            signer_note: Some("Ledger #2".to_string()),
+        };
+        sqlx::query("INSERT INTO proposal_signatures (proposal_id, signer_pkh, signed_tx_json, signed_at, delegate_pkh, signer_note) VALUES (?, ?, ?, ?, ?, ?)")
+            .bind(&signature.proposal_id)
+            .bind(&signature.signer_pkh)
+            .bind(&signature.signed_tx_json)
+            .bind(&signature.signed_at)
+            .bind(&signature.delegate_pkh)
+            .bind(&signature.signer_note)
+            .execute(&pool.write)
+            .await
+            .unwrap();
+        let read: ProposalSignature = sqlx::query_as("SELECT * FROM proposal_signatures")
+            .fetch_one(&pool.read)
+            .await
+            .unwrap();
+        assert_eq!(read, signature);
+        
+        let comment = ProposalComment {
+            id: "C1".to_string(),
+            proposal_id: "P1".to_string(),
+            author_pkh: Pkh::parse("A").unwrap(),
+            body: "Fee looks high — can we wait?".to_string(),
+            created_at: now.clone(),
+        };
+        sqlx::query("INSERT INTO proposal_comments (id, proposal_id, author_pkh, body, created_at) VALUES (?, ?, ?, ?, ?)")
+            .bind(&comment.id)
+            .bind(&comment.proposal_id)
+            .bind(&comment.author_pkh)
+            .bind(&comment.body)
+            .bind(&comment.created_at)
+            .execute(&pool.write)
+            .await
+            .unwrap();
+        let read: ProposalComment = sqlx::query_as("SELECT * FROM proposal_comments")
+            .fetch_one(&pool.read)
+            .await
+            .unwrap();
+        assert_eq!(read, comment);
+        
+        let delegation = Delegation {
+            id: "D1".to_string(),
+            lock_root_hash: LockRootHash::parse("L1").unwrap(),
+            from_pkh: Pkh::parse("B").unwrap(),
+            to_pkh: Pkh::parse("C").unwrap(),
+            expires_at: "2099-01-01T00:00:00.000000Z".to_string(),
+            created_at: now,
+            revoked_at: None,
+        };
+        sqlx::query("INSERT INTO delegations (id, lock_root_hash, from_pkh, to_pkh, expires_at, created_at) VALUES (?, ?, ?, ?, ?, ?)")
+            .bind(&delegation.id)
+            .bind(&delegation.lock_root_hash)
+            .bind(&delegation.from_pkh)
+            .bind(&delegation.to_pkh)
+            .bind(&delegation.expires_at)
+            .bind(&delegation.created_at)
+            .execute(&pool.write)
+            .await
+            .unwrap();
+        let read: Delegation = sqlx::query_as("SELECT * FROM delegations")
+            .fetch_one(&pool.read)
+            .await
+            .unwrap();
+        assert_eq!(read, delegation);
+    }
+
+    #[tokio::test]
+    async fn history_round_trips_every_status() {
+        let pool = create_memory_pool().await.unwrap();
+        insert_lock(&pool).await;
+        
+        for (i, status) in [TransactionStatus::Broadcast, TransactionStatus::Confirmed, TransactionStatus::Failed].into_iter().enumerate() {
+            let confirmed = status == TransactionStatus::Confirmed;
+            let written = TransactionHistory {
+                id: format!("H{}", i),
+                tx_id: format!("tx-{}", i),
+                lock_root_hash: LockRootHash::parse("L1").unwrap(),
+                proposer_pkh: Pkh::parse("A").unwrap(),
+                status,
+                total_input_nicks: 100,
+                seeds_json: r#"[{"recipient":"R1","amount_nicks":50}]"#.to_string(),
+                signers_json: r#"["A","B"]"#.to_string(),
+                created_at: now_rfc3339(),
+                broadcast_at: Some(now_rfc3339()),
+                confirmed_at: confirmed.then(now_rfc3339),
+                block_height: confirmed.then_some(1234),
+                confirmations: if confirmed { 6 } else { 0 },
+            };
+            sqlx::query(
+                "INSERT INTO transaction_history (id, tx_id, lock_root_hash, proposer_pkh, status, total_input_nicks,
+                 seeds_json, signers_json, created_at, broadcast_at, confirmed_at, block_height, confirmations)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+            )
+            .bind(&written.id)
+            .bind(&written.tx_id)
+            .bind(&written.lock_root_hash)
+            .bind(&written.proposer_pkh)
+            .bind(written.status.as_str())
+            .bind(written.total_input_nicks)
+            .bind(&written.seeds_json)
+            .bind(&written.signers_json)
+            .bind(&written.created_at)
+            .bind(&written.broadcast_at)
+            .bind(&written.confirmed_at)
+            .bind(written.block_height)
+            .bind(written.confirmations)
+            .execute(&pool.write)
+            .await
+            .unwrap();
+            
+            let read: TransactionHistory = sqlx::query_as("SELECT * FROM transaction_history WHERE id = ?")
+                .bind(&written.id)
+                .fetch_one(&pool.read)
+                .await
+                .unwrap();
+            assert_eq!(read, written);
+        }
+    }
+}
@@ -1,25 +1,99 @@
-use sqlx::{sqlite::{SqliteConnectOptions, SqlitePoolOptions}, Pool, Sqlite};
+use sqlx::{migrate::Migrator, sqlite::{SqliteConnectOptions, SqlitePoolOptions}, Pool, Sqlite};
+use std::path::Path;
 use std::str::FromStr;
 use std::time::Duration;
 
-pub type DbPool = Pool<Sqlite>;
+pub static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
 
-pub async fn create_pool(database_url: &str) -> Result<DbPool, sqlx::Error> {
-    // Create parent directories if they don't exist
-    if let Some(path_str) = database_url.strip_prefix("sqlite:") {
-        let path = std::path::Path::new(path_str);
-        if let Some(parent) = path.parent() {
-            std::fs::create_dir_all(parent)?;
-        }
+/// Primary pool for writes, plus the pool GET handlers read from (a read-only
+/// connection to `READ_DATABASE_URL` when configured, otherwise the primary)
+#[derive(Clone)]
+pub struct DbPool {
+    pub write: Pool<Sqlite>,
+    pub read: Pool<Sqlite>,
+}
+
+/// `statement_cache_capacity` is per connection; 0 disables prepared-statement reuse
+pub async fn create_pool(
+    database_url: &str,
+    acquire_timeout: Duration,
+    statement_cache_capacity: usize,
+) -> Result<Pool<Sqlite>, sqlx::Error> {
+    // Create parent directories if they don't exist (file-backed databases only)
+    if let Some(parent) = sqlite_file_path(database_url).and_then(Path::parent) {
+        std::fs::create_dir_all(parent)?;
     }
     
+    // Foreign keys are sqlx's default for SQLite, but signature/comment integrity relies on
+    // them (no orphan rows for missing proposals), so state it explicitly
+    let options = SqliteConnectOptions::from_str(database_url)?
+        .create_if_missing(true)
+        .foreign_keys(true)
+        .statement_cache_capacity(statement_cache_capacity);
+    
+    SqlitePoolOptions::new()
+        .max_connections(5)
+        .acquire_timeout(acquire_timeout)
+        .connect_with(options)
+        .await
+}
+
+/// Filesystem path of a SQLite URL (`sqlite:path`, `sqlite://path`, `sqlite:///abs/path`,
+/// optionally with `?query`), or None for in-memory databases
+fn sqlite_file_path(database_url: &str) -> Option<&Path> {
+    let rest = database_url
+        .strip_prefix("sqlite://")
+        .or_else(|| database_url.strip_prefix("sqlite:"))?;
+    let (path, query) = rest.split_once('?').unwrap_or((rest, ""));
+    let in_memory = path.is_empty() || path == ":memory:" || query.split('&').any(|kv| kv == "mode=memory");
+    if in_memory { None } else { Some(Path::new(path)) }
+}
+
+/// Opens an existing database read-only; for SQLite this is typically the primary file
+pub async fn create_read_pool(
+    database_url: &str,
+    acquire_timeout: Duration,
+    statement_cache_capacity: usize,
+) -> Result<Pool<Sqlite>, sqlx::Error> {
     let options = SqliteConnectOptions::from_str(database_url)?
-        .create_if_missing(true);
+        .read_only(true)
+        .statement_cache_capacity(statement_cache_capacity);
     
     SqlitePoolOptions::new()
         .max_connections(5)
-        .acquire_timeout(Duration::from_secs(10))
+        .acquire_timeout(acquire_timeout)
         .connect_with(options)
         .await
 }
 
+/// Isolated, migrated in-memory database for handler tests
+#[cfg(test)]
+pub async fn create_memory_pool() -> Result<DbPool, sqlx::Error> {
+    crate::init_tracing();
+    // Every in-memory connection is its own database, so pin the pool to a single
+    // connection that is never recycled
+    let options = SqliteConnectOptions::from_str("sqlite::memory:")?;
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect_with(options)
+        .await?;
+    MIGRATOR.run(&pool).await?;
+    Ok(DbPool { write: pool.clone(), read: pool })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn memory_pool_is_migrated() {
+        let pool = create_memory_pool().await.unwrap();
+        let locks: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM locks")
+            .fetch_one(&pool.read)
+            .await
+            .unwrap();
+        assert_eq!(locks, 0);
+    }
+}
@@ -0,0 +1,105 @@
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+
+/// Stored timestamps are always UTC with a `Z` suffix and exactly six fractional digits,
+/// so comparing them as TEXT (ORDER BY, `<`, MAX) matches chronological order
+pub fn format_rfc3339(ts: DateTime<Utc>) -> String {
+    ts.to_rfc3339_opts(SecondsFormat::Micros, true)
+}
+
+pub fn now_rfc3339() -> String {
+    format_rfc3339(Utc::now())
+}
+
+/// `now_rfc3339()` shifted forward, for comparisons that allow for client clock skew
+pub fn rfc3339_secs_from_now(secs: u64) -> String {
+    format_rfc3339(Utc::now() + Duration::seconds(secs as i64))
+}
+
+/// Timezone-safe normalization of a client-supplied RFC3339 timestamp (any offset or
+/// precision) into the canonical stored form
+pub fn canonicalize_rfc3339(value: &str) -> Result<String, chrono::ParseError> {
+    let parsed = DateTime::parse_from_rfc3339(value)?;
+    Ok(format_rfc3339(parsed.with_timezone(&Utc)))
+}
+
+/// Parses a relative window such as `30m`, `24h`, `7d` or `2w` (positive whole units)
+pub fn parse_relative_duration(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    let split = value.find(|c: char| !c.is_ascii_digit())?;
+    let (amount, unit) = value.split_at(split);
+    let amount: i64 = amount.parse().ok().filter(|n| *n > 0)?;
+    match unit {
+        "s" => Duration::try_seconds(amount),
+        "m" => Duration::try_minutes(amount),
+        "h" => Duration::try_hours(amount),
+        "d" => Duration::try_days(amount),
+        "w" => Duration::try_weeks(amount),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn canonical_form_is_fixed_width_utc() {
+        let ts = Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap();
+        assert_eq!(format_rfc3339(ts), "2024-01-02T03:04:05.000000Z");
+        assert_eq!(format_rfc3339(ts + Duration::nanoseconds(123_456_789)), "2024-01-02T03:04:05.123456Z");
+        assert_eq!(now_rfc3339().len(), "2024-01-02T03:04:05.000000Z".len());
+    }
+
+    #[test]
+    fn canonicalize_converts_offsets_to_utc() {
+        assert_eq!(canonicalize_rfc3339("2024-01-02T05:04:05+02:00").unwrap(), "2024-01-02T03:04:05.000000Z");
+        assert_eq!(canonicalize_rfc3339("2024-01-02T03:04:05.5+00:00").unwrap(), "2024-01-02T03:04:05.500000Z");
+        assert_eq!(canonicalize_rfc3339("2024-01-01T22:04:05.123456789-05:00").unwrap(), "2024-01-02T03:04:05.123456Z");
+        assert!(canonicalize_rfc3339("2024-01-02 03:04:05").is_err());
+    }
+
+    #[test]
+    fn lexical_order_matches_chronological_order() {
+        let base = Utc.with_ymd_and_hms(2024, 12, 31, 23, 59, 59).unwrap();
+        // Offsets chosen to hit whole seconds, sub-millisecond steps and day/year rollovers
+        let offsets = [
+            Duration::zero(),
+            Duration::nanoseconds(1_000),
+            Duration::microseconds(999),
+            Duration::milliseconds(1),
+            Duration::milliseconds(100),
+            Duration::seconds(1),
+            Duration::minutes(1),
+            Duration::days(1),
+            Duration::days(400),
+            Duration::seconds(-1),
+            Duration::microseconds(-1),
+            Duration::days(-365),
+        ];
+        let mut times: Vec<DateTime<Utc>> = offsets.iter().map(|d| base + *d).collect();
+        let mut rendered: Vec<String> = times.iter().map(|t| format_rfc3339(*t)).collect();
+        times.sort();
+        rendered.sort();
+        assert_eq!(rendered, times.iter().map(|t| format_rfc3339(*t)).collect::<Vec<_>>());
+
+        // The same instant supplied with different offsets sorts identically once canonicalized
+        let later = canonicalize_rfc3339("2025-01-01T00:30:00+01:00").unwrap();
+        let earlier = canonicalize_rfc3339("2024-12-31T23:00:00Z").unwrap();
+        assert!(earlier < later);
+        assert!("2025-01-01T00:30:00+01:00" > "2024-12-31T23:45:00Z"); // raw strings would misorder
+        assert!(canonicalize_rfc3339("2025-01-01T00:30:00+01:00").unwrap() < canonicalize_rfc3339("2024-12-31T23:45:00Z").unwrap());
+    }
+
+    #[test]
+    fn parses_relative_durations() {
+        assert_eq!(parse_relative_duration("24h"), Some(Duration::hours(24)));
+        assert_eq!(parse_relative_duration("7d"), Some(Duration::days(7)));
+        assert_eq!(parse_relative_duration("30d"), Some(Duration::days(30)));
+        assert_eq!(parse_relative_duration("15m"), Some(Duration::minutes(15)));
+        assert_eq!(parse_relative_duration("2w"), Some(Duration::weeks(2)));
+        for invalid in ["", "h", "24", "0h", "-1d", "1.5h", "1y", "24 h", "99999999999999999999d"] {
+            assert_eq!(parse_relative_duration(invalid), None, "{:?}", invalid);
+        }
+    }
+}
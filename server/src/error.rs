@@ -1,10 +1,24 @@
 use axum::{
-    http::StatusCode,
+    http::{header, StatusCode},
     response::{IntoResponse, Response},
     Json,
 };
-use serde_json::json;
+use serde::Serialize;
 use thiserror::Error;
+use utoipa::ToSchema;
+
+/// Seconds a client should wait before retrying after the connection pool timed out
+const POOL_TIMEOUT_RETRY_AFTER_SECS: u64 = 1;
+
+/// Standard error envelope returned by every endpoint
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ErrorBody {
+    pub error: String,
+    pub message: String,
+    /// Only present (true) when the same request may succeed if retried later
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub retryable: bool,
+}
 
 #[derive(Error, Debug)]
 pub enum AppError {
@@ -14,14 +28,15 @@ pub enum AppError {
     #[error("Invalid input: {0}")]
     InvalidInput(String),
 
-    #[allow(dead_code)]
     #[error("Unauthorized: {0}")]
     Unauthorized(String),
 
     #[error("Not found: {0}")]
     NotFound(String),
 
-    #[allow(dead_code)]
+    #[error("Service unavailable: {0}")]
+    ServiceUnavailable(String),
+
     #[error("Internal server error: {0}")]
     Internal(String),
 }
@@ -29,6 +44,20 @@ pub enum AppError {
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
         let (status, error_code, error_message) = match self {
+            // Every connection was busy for the whole acquire timeout: transient under load
+            AppError::Database(sqlx::Error::PoolTimedOut) => {
+                tracing::warn!("Database pool exhausted; asking the client to retry");
+                let body = Json(ErrorBody {
+                    error: "DATABASE_BUSY".to_string(),
+                    message: "The database is busy; retry shortly".to_string(),
+                    retryable: true,
+                });
+                return (
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    [(header::RETRY_AFTER, POOL_TIMEOUT_RETRY_AFTER_SECS.to_string())],
+                    body,
+                ).into_response();
+            }
             AppError::Database(ref e) => {
                 tracing::error!("Database error: {}", e);
                 (StatusCode::INTERNAL_SERVER_ERROR, "DATABASE_ERROR", "Database error")
@@ -36,18 +65,45 @@ impl IntoResponse for AppError {
             AppError::InvalidInput(ref msg) => (StatusCode::BAD_REQUEST, "INVALID_INPUT", msg.as_str()),
             AppError::Unauthorized(ref msg) => (StatusCode::UNAUTHORIZED, "UNAUTHORIZED", msg.as_str()),
             AppError::NotFound(ref msg) => (StatusCode::NOT_FOUND, "NOT_FOUND", msg.as_str()),
+            AppError::ServiceUnavailable(ref msg) => (StatusCode::SERVICE_UNAVAILABLE, "SERVICE_UNAVAILABLE", msg.as_str()),
             AppError::Internal(ref msg) => {
                 tracing::error!("Internal error: {}", msg);
                 (StatusCode::INTERNAL_SERVER_ERROR, "INTERNAL_ERROR", "Internal server error")
             }
         };
 
-        let body = Json(json!({
-            "error": error_code,
-            "message": error_message,
-        }));
+        let body = Json(ErrorBody {
+            error: error_code.to_string(),
+            message: error_message.to_string(),
+            retryable: false,
+        });
 
         (status, body).into_response()
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn body_json(response: Response) -> serde_json::Value {
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn pool_timeout_is_a_retryable_503() {
+        let response = AppError::Database(sqlx::Error::PoolTimedOut).into_response();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(response.headers()[header::RETRY_AFTER], "1");
+        let body = body_json(response).await;
+        assert_eq!(body["error"], "DATABASE_BUSY");
+        assert_eq!(body["retryable"], true);
+
+        let response = AppError::Database(sqlx::Error::RowNotFound).into_response();
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+        assert!(response.headers().get(header::RETRY_AFTER).is_none());
+        assert!(body_json(response).await.get("retryable").is_none());
+    }
+}
@@ -0,0 +1,318 @@
+use std::net::IpAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use hmac::{Hmac, Mac};
+use serde::Serialize;
+use sha2::Sha256;
+use sqlx::{Pool, Sqlite};
+
+use crate::config::Config;
+use crate::db::{LockRootHash, Pkh};
+
+/// Proposal lifecycle events published to the configured sink
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ProposalEvent {
+    Created {
+        proposal_id: String,
+        tx_id: String,
+        lock_root_hash: LockRootHash,
+        proposer_pkh: Pkh,
+    },
+    Signed {
+        proposal_id: String,
+        signer_pkh: Pkh,
+        signatures_collected: i32,
+    },
+    SignatureWithdrawn {
+        proposal_id: String,
+        signer_pkh: Pkh,
+        signatures_collected: i32,
+    },
+    Ready {
+        proposal_id: String,
+        lock_root_hash: LockRootHash,
+    },
+    Broadcast {
+        proposal_id: String,
+        tx_id: String,
+        history_id: String,
+    },
+    /// Admin override that marked a proposal ready regardless of signature count
+    ForcedReady {
+        proposal_id: String,
+        lock_root_hash: LockRootHash,
+        actor: String,
+        reason: String,
+    },
+    Rebroadcast {
+        history_id: String,
+        tx_id: String,
+        lock_root_hash: LockRootHash,
+    },
+    Confirmed {
+        tx_id: String,
+        block_height: i64,
+    },
+}
+
+impl ProposalEvent {
+    /// What identifies the wallet this event belongs to
+    fn wallet_ref(&self) -> WalletRef<'_> {
+        match self {
+            ProposalEvent::Created { lock_root_hash, .. }
+            | ProposalEvent::Ready { lock_root_hash, .. }
+            | ProposalEvent::ForcedReady { lock_root_hash, .. }
+            | ProposalEvent::Rebroadcast { lock_root_hash, .. } => WalletRef::Lock(lock_root_hash),
+            ProposalEvent::Signed { proposal_id, .. }
+            | ProposalEvent::SignatureWithdrawn { proposal_id, .. }
+            | ProposalEvent::Broadcast { proposal_id, .. } => WalletRef::Proposal(proposal_id),
+            ProposalEvent::Confirmed { tx_id, .. } => WalletRef::Tx(tx_id),
+        }
+    }
+}
+
+enum WalletRef<'a> {
+    Lock(&'a str),
+    Proposal(&'a str),
+    Tx(&'a str),
+}
+
+/// Destination for lifecycle events. `emit` must not block the handler;
+/// network sinks deliver in a background task and only log failures.
+pub trait EventSink: Send + Sync {
+    fn emit(&self, event: ProposalEvent);
+}
+
+pub struct LogSink;
+
+impl EventSink for LogSink {
+    fn emit(&self, event: ProposalEvent) {
+        tracing::info!(?event, "Proposal event");
+    }
+}
+
+pub struct WebhookSink {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl EventSink for WebhookSink {
+    fn emit(&self, event: ProposalEvent) {
+        let (client, url) = (self.client.clone(), self.url.clone());
+        tokio::spawn(async move { deliver_webhook(&client, &url, None, &event).await });
+    }
+}
+
+const WEBHOOK_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+const WEBHOOK_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Bounded so a slow or unresponsive receiver can't pile up delivery tasks
+fn webhook_client() -> reqwest::Result<reqwest::Client> {
+    reqwest::Client::builder()
+        .connect_timeout(WEBHOOK_CONNECT_TIMEOUT)
+        .timeout(WEBHOOK_REQUEST_TIMEOUT)
+        .build()
+}
+
+/// Refuses wallet webhook urls whose host is or resolves to a loopback, private or link-local
+/// address, so a participant can't aim deliveries at services on the server's own network.
+/// Err is the reason, for the caller to wrap
+pub async fn check_webhook_target(url: &reqwest::Url) -> Result<(), String> {
+    let host = url.host_str().ok_or("url has no host")?;
+    let addrs: Vec<IpAddr> = match host.trim_start_matches('[').trim_end_matches(']').parse() {
+        Ok(ip) => vec![ip],
+        Err(_) => {
+            let port = url.port_or_known_default().unwrap_or(80);
+            tokio::net::lookup_host((host, port))
+                .await
+                .map_err(|e| format!("cannot resolve {}: {}", host, e))?
+                .map(|addr| addr.ip())
+                .collect()
+        }
+    };
+    match addrs.into_iter().find(|ip| !is_public(*ip)) {
+        Some(ip) => Err(format!("{} is not a public address", ip)),
+        None => Ok(()),
+    }
+}
+
+fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => !(ip.is_loopback() || ip.is_private() || ip.is_link_local() || ip.is_unspecified() || ip.is_broadcast()),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(v4) => is_public(v4.into()),
+            None => !(ip.is_loopback() || ip.is_unique_local() || ip.is_unicast_link_local() || ip.is_unspecified()),
+        },
+    }
+}
+
+/// POSTs the event as JSON. With a secret, `X-Agora-Signature: sha256=<hex>` carries the
+/// HMAC-SHA256 of the body so the receiver can verify it came from this server
+async fn deliver_webhook(client: &reqwest::Client, url: &str, secret: Option<&str>, event: &ProposalEvent) {
+    let body = match serde_json::to_vec(event) {
+        Ok(body) => body,
+        Err(e) => {
+            tracing::warn!("Failed to serialize event: {}", e);
+            return;
+        }
+    };
+    let mut request = client.post(url).header(reqwest::header::CONTENT_TYPE, "application/json");
+    if let Some(secret) = secret {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+        mac.update(&body);
+        request = request.header("X-Agora-Signature", format!("sha256={}", hex::encode(mac.finalize().into_bytes())));
+    }
+    let result = request.body(body).send().await.and_then(|r| r.error_for_status());
+    if let Err(e) = result {
+        tracing::warn!(url = %url, "Failed to deliver event webhook: {}", e);
+    }
+}
+
+/// Sends each event to its wallet's own webhook (`PUT /api/multisigs/{lock_root_hash}/webhook`)
+/// when one is configured, otherwise to `fallback`. Multi-wallet proposals route by their
+/// primary wallet. The lookup runs in a background task, so delivery order is best-effort.
+/// The target is re-checked before each delivery, since DNS may have changed since it was set
+pub struct WalletRoutingSink {
+    pool: Pool<Sqlite>,
+    client: reqwest::Client,
+    allow_private_targets: bool,
+    fallback: Arc<dyn EventSink>,
+}
+
+impl EventSink for WalletRoutingSink {
+    fn emit(&self, event: ProposalEvent) {
+        let (pool, client, fallback) = (self.pool.clone(), self.client.clone(), self.fallback.clone());
+        let allow_private_targets = self.allow_private_targets;
+        tokio::spawn(async move {
+            match wallet_webhook(&pool, &event).await {
+                Ok(Some((url, secret))) => {
+                    if !allow_private_targets {
+                        let checked = match reqwest::Url::parse(&url) {
+                            Ok(target) => check_webhook_target(&target).await,
+                            Err(e) => Err(e.to_string()),
+                        };
+                        if let Err(e) = checked {
+                            tracing::warn!(url = %url, "Refusing to deliver event webhook: {}", e);
+                            return;
+                        }
+                    }
+                    deliver_webhook(&client, &url, secret.as_deref(), &event).await
+                }
+                Ok(None) => fallback.emit(event),
+                Err(e) => {
+                    tracing::warn!("Failed to look up wallet webhook, using the default sink: {}", e);
+                    fallback.emit(event);
+                }
+            }
+        });
+    }
+}
+
+/// `(webhook_url, webhook_secret)` of the event's wallet, if it has a webhook
+async fn wallet_webhook(pool: &Pool<Sqlite>, event: &ProposalEvent) -> Result<Option<(String, Option<String>)>, sqlx::Error> {
+    let (sql, key) = match event.wallet_ref() {
+        WalletRef::Lock(lock_root_hash) => (
+            "SELECT webhook_url, webhook_secret FROM locks WHERE lock_root_hash = ?",
+            lock_root_hash,
+        ),
+        WalletRef::Proposal(proposal_id) => (
+            "SELECT l.webhook_url, l.webhook_secret FROM locks l
+             JOIN proposals p ON p.lock_root_hash = l.lock_root_hash WHERE p.id = ?",
+            proposal_id,
+        ),
+        WalletRef::Tx(tx_id) => (
+            "SELECT l.webhook_url, l.webhook_secret FROM locks l
+             JOIN transaction_history h ON h.lock_root_hash = l.lock_root_hash WHERE h.tx_id = ? LIMIT 1",
+            tx_id,
+        ),
+    };
+    let row: Option<(Option<String>, Option<String>)> = sqlx::query_as(sql)
+        .bind(key)
+        .fetch_optional(pool)
+        .await?;
+    Ok(row.and_then(|(url, secret)| url.map(|url| (url, secret))))
+}
+
+#[cfg(feature = "nats")]
+pub struct NatsSink {
+    client: async_nats::Client,
+    subject: String,
+}
+
+#[cfg(feature = "nats")]
+impl EventSink for NatsSink {
+    fn emit(&self, event: ProposalEvent) {
+        let payload = match serde_json::to_vec(&event) {
+            Ok(payload) => payload,
+            Err(e) => {
+                tracing::warn!("Failed to serialize event: {}", e);
+                return;
+            }
+        };
+        let client = self.client.clone();
+        let subject = self.subject.clone();
+        tokio::spawn(async move {
+            if let Err(e) = client.publish(subject, payload.into()).await {
+                tracing::warn!("Failed to publish event to NATS: {}", e);
+            }
+        });
+    }
+}
+
+/// Builds the sink selected by `EVENT_SINK` (validated in `Config::validate`), wrapped so
+/// wallets with their own webhook receive their events there instead
+pub async fn create_sink(config: &Config, pool: Pool<Sqlite>) -> Result<Arc<dyn EventSink>, Box<dyn std::error::Error>> {
+    let fallback = create_default_sink(config).await?;
+    Ok(Arc::new(WalletRoutingSink {
+        pool,
+        client: webhook_client()?,
+        allow_private_targets: config.webhook_allow_private_targets,
+        fallback,
+    }))
+}
+
+async fn create_default_sink(config: &Config) -> Result<Arc<dyn EventSink>, Box<dyn std::error::Error>> {
+    match config.event_sink.as_str() {
+        "webhook" => {
+            let url = config.event_webhook_url.clone().ok_or("EVENT_WEBHOOK_URL is not set")?;
+            Ok(Arc::new(WebhookSink { client: webhook_client()?, url }))
+        }
+        #[cfg(feature = "nats")]
+        "nats" => {
+            let url = config.nats_url.as_deref().ok_or("NATS_URL is not set")?;
+            let client = async_nats::connect(url).await?;
+            Ok(Arc::new(NatsSink { client, subject: config.nats_subject.clone() }))
+        }
+        _ => Ok(Arc::new(LogSink)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn webhook_targets_must_be_public() {
+        for url in [
+            "http://127.0.0.1/hook",
+            "http://10.1.2.3/hook",
+            "http://192.168.0.10:8080/hook",
+            "http://169.254.169.254/latest/meta-data",
+            "http://[::1]/hook",
+            "http://[fd00::1]/hook",
+            "http://[fe80::1]/hook",
+            "http://[::ffff:127.0.0.1]/hook",
+            "http://0.0.0.0/hook",
+            "http://localhost/hook",
+        ] {
+            let url = reqwest::Url::parse(url).unwrap();
+            assert!(check_webhook_target(&url).await.is_err(), "{} should be refused", url);
+        }
+        for url in ["https://93.184.215.14/hook", "https://[2606:4700::1111]/hook"] {
+            let url = reqwest::Url::parse(url).unwrap();
+            assert!(check_webhook_target(&url).await.is_ok(), "{} should be allowed", url);
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use std::time::Duration;
+
+use crate::db::{now_rfc3339, DbPool, ProposalStatus};
+
+/// Periodically marks pending proposals whose `expires_at` (including any signing window
+/// applied at creation) has passed as expired. Ready proposals are left alone
+pub fn spawn_expiry_job(pool: DbPool, interval: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match expire_overdue(&pool).await {
+                Ok(0) => {}
+                Ok(expired) => tracing::info!(expired, "Expired overdue proposals"),
+                Err(e) => tracing::error!("Proposal expiry sweep failed: {}", e),
+            }
+        }
+    });
+}
+
+async fn expire_overdue(pool: &DbPool) -> Result<u64, sqlx::Error> {
+    let now = now_rfc3339();
+    let expired = sqlx::query(
+        "UPDATE proposals SET status = ?, updated_at = ? WHERE status = ? AND expires_at <= ?"
+    )
+    .bind(ProposalStatus::Expired.as_str())
+    .bind(&now)
+    .bind(ProposalStatus::Pending.as_str())
+    .bind(&now)
+    .execute(&pool.write)
+    .await?
+    .rows_affected();
+    
+    Ok(expired)
+}
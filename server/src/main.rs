@@ -2,62 +2,195 @@ mod api;
 mod config;
 mod db;
 mod error;
+mod events;
+mod expiry;
+mod retention;
+#[cfg(unix)]
+mod unix_socket;
+mod validation;
 
 use dotenv::dotenv;
 use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
 use tower::ServiceBuilder;
-use tower_http::cors::{CorsLayer, Any, AllowOrigin};
+use tower_http::compression::CompressionLayer;
+use tower_http::cors::{CorsLayer, AllowOrigin};
 use tower_http::trace::TraceLayer;
-use tracing_subscriber;
 
-use config::Config;
-use db::create_pool;
+use api::AppState;
+use api::maintenance::ReadOnlyFlag;
+use api::pagination::TOTAL_COUNT_HEADER;
+use config::{BindTarget, Config};
+use db::{adopt_revised_migrations, create_pool, create_read_pool, describe_migration_error, verify_migrations, DbPool, MIGRATOR};
+
+/// Resolves on Ctrl-C or (on Unix) SIGTERM, starting a graceful shutdown
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            tracing::error!("Failed to listen for Ctrl-C: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(e) => {
+                tracing::error!("Failed to listen for SIGTERM: {}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+    
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+    }
+    tracing::info!("Shutting down");
+}
+
+/// Installs the global subscriber. Safe to call more than once (tests set up the app
+/// repeatedly in one process); later calls keep the first subscriber
+pub(crate) fn init_tracing() {
+    let builder = tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| "agora_gateway=info,tower_http=info".into()),
+        );
+    // Route test logs through the harness so they're only shown for failing tests
+    #[cfg(test)]
+    let builder = builder.with_test_writer();
+    let _ = builder.try_init();
+}
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Load environment variables
     dotenv().ok();
 
-    // Initialize tracing
-    tracing_subscriber::fmt()
-        .with_env_filter(
-            tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| "agora_gateway=info,tower_http=info".into()),
-        )
-        .init();
+    init_tracing();
 
-    // Load configuration
-    let config = Config::from_env();
+    // Load and validate configuration, reporting every problem at once
+    let mut config = Config::load();
+    if let Err(errors) = config.validate() {
+        for e in &errors {
+            tracing::error!("Config error: {}", e);
+        }
+        return Err(format!("Invalid configuration ({} problem(s))", errors.len()).into());
+    }
+    let config = Arc::new(config);
 
     // Create database pool
     tracing::info!("Connecting to database: {}", config.database_url);
-    let pool = create_pool(&config.database_url).await?;
+    let write_pool = create_pool(&config.database_url, config.db_acquire_timeout(), config.statement_cache_capacity).await?;
+    
+    if config.migrations_verify_only {
+        let report = verify_migrations(&write_pool, &MIGRATOR).await?;
+        tracing::info!(applied = report.applied, "Checked database migrations");
+        for (label, versions) in [
+            ("pending", &report.pending),
+            ("partially applied", &report.dirty),
+            ("modified since applied", &report.modified),
+            ("unknown to this build", &report.unknown),
+        ] {
+            if !versions.is_empty() {
+                tracing::warn!(?versions, "Migrations {}", label);
+            }
+        }
+        if !report.is_current() {
+            return Err("Database migrations are not up to date".into());
+        }
+        tracing::info!("Database migrations are up to date");
+        return Ok(());
+    }
     
     // Run migrations
     tracing::info!("Running database migrations...");
-    sqlx::migrate!("./migrations")
-        .run(&pool)
-        .await?;
+    adopt_revised_migrations(&write_pool, &MIGRATOR).await?;
+    if let Err(e) = MIGRATOR.run(&write_pool).await {
+        let message = describe_migration_error(&MIGRATOR, &e);
+        tracing::error!("{}", message);
+        return Err(message.into());
+    }
+
+    // Read pool is opened after migrations so the database file exists
+    let read_pool = match &config.read_database_url {
+        Some(url) => {
+            tracing::info!("Connecting to read database: {}", url);
+            create_read_pool(url, config.db_acquire_timeout(), config.statement_cache_capacity).await?
+        }
+        None => write_pool.clone(),
+    };
+    let pool = DbPool { write: write_pool, read: read_pool };
+    
+    if let Some(days) = config.history_retention_days {
+        tracing::info!("Archiving transaction history older than {} days", days);
+        retention::spawn_archival_job(pool.clone(), days, Duration::from_secs(config.archival_interval_secs));
+    }
+    expiry::spawn_expiry_job(pool.clone(), Duration::from_secs(config.expiry_sweep_interval_secs));
+    let events = events::create_sink(&config, pool.read.clone()).await?;
+    tracing::info!("Publishing proposal events to the {} sink", config.event_sink);
+    if config.read_only {
+        tracing::warn!("Starting in read-only maintenance mode; mutating requests will be rejected");
+    }
+    let state = AppState {
+        pool,
+        config: config.clone(),
+        events,
+        read_only: ReadOnlyFlag::new(config.read_only),
+    };
 
     // Create router
-    let app = api::create_router(pool)
+    let app = api::create_router(state)
         .layer(
             ServiceBuilder::new()
                 .layer(TraceLayer::new_for_http())
+                // With COMPRESSION=false every encoding is disabled and bodies pass through as-is
+                .layer(
+                    CompressionLayer::new()
+                        .gzip(config.compression)
+                        .br(config.compression),
+                )
                 .layer(
                     CorsLayer::new()
-                        .allow_origin(AllowOrigin::exact(config.cors_origin.parse().map_err(|e| format!("Invalid CORS origin: {}", e))?))
-                        .allow_methods(Any)
-                        .allow_headers(Any),
+                        .allow_origin(AllowOrigin::exact(config.cors_allow_origin()?))
+                        .allow_methods(config.cors_allow_methods()?)
+                        .allow_headers(config.cors_allow_headers()?)
+                        .allow_credentials(config.cors_allow_credentials)
+                        // Cross-origin JS can only read listed headers; ETag and Last-Modified
+                        // drive If-None-Match / If-Modified-Since
+                        .expose_headers([
+                            axum::http::HeaderName::from_static(TOTAL_COUNT_HEADER),
+                            axum::http::header::ETAG,
+                            axum::http::header::LAST_MODIFIED,
+                        ]),
                 ),
         );
 
     // Start server
-    let addr = SocketAddr::from(([0, 0, 0, 0], config.api_port));
-    tracing::info!("Server starting on {}", addr);
-    
-    let listener = tokio::net::TcpListener::bind(addr).await?;
-    axum::serve(listener, app).await?;
+    match config.bind_target()? {
+        BindTarget::Tcp(addr) => {
+            tracing::info!("Server starting on {}", addr);
+            let listener = tokio::net::TcpListener::bind(addr).await?;
+            // Peer addresses feed the audit log's source_ip
+            axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
+                .with_graceful_shutdown(shutdown_signal())
+                .await?;
+        }
+        #[cfg(unix)]
+        BindTarget::Unix(path) => {
+            // No peer IP on a Unix socket, so audit entries have no source_ip
+            tracing::info!("Server starting on unix:{}", path.display());
+            unix_socket::serve(&path, app, shutdown_signal()).await?;
+        }
+        #[cfg(not(unix))]
+        BindTarget::Unix(_) => return Err("Unix socket listeners are only supported on Unix".into()),
+    }
 
     Ok(())
 }
@@ -0,0 +1,47 @@
+use std::time::Duration;
+
+use crate::db::{format_rfc3339, DbPool};
+
+/// Periodically moves transaction history older than `retention_days` into `archived_history`
+pub fn spawn_archival_job(pool: DbPool, retention_days: u64, interval: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match archive_history(&pool, retention_days).await {
+                Ok(0) => {}
+                Ok(moved) => tracing::info!(moved, retention_days, "Archived transaction history"),
+                Err(e) => tracing::error!("History archival failed: {}", e),
+            }
+        }
+    });
+}
+
+async fn archive_history(pool: &DbPool, retention_days: u64) -> Result<u64, sqlx::Error> {
+    let now = chrono::Utc::now();
+    let cutoff = format_rfc3339(now - chrono::Duration::days(retention_days as i64));
+    
+    let mut tx = pool.write.begin().await?;
+    let moved = sqlx::query(
+        "INSERT INTO archived_history (id, tx_id, lock_root_hash, proposer_pkh, status,
+         total_input_nicks, seeds_json, signers_json, created_at, broadcast_at, confirmed_at,
+         block_height, confirmations, archived_at)
+         SELECT id, tx_id, lock_root_hash, proposer_pkh, status,
+                total_input_nicks, seeds_json, signers_json, created_at, broadcast_at, confirmed_at,
+                block_height, confirmations, ?
+         FROM transaction_history WHERE COALESCE(broadcast_at, created_at) < ?"
+    )
+    .bind(format_rfc3339(now))
+    .bind(&cutoff)
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+    
+    sqlx::query("DELETE FROM transaction_history WHERE COALESCE(broadcast_at, created_at) < ?")
+        .bind(&cutoff)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+    
+    Ok(moved)
+}
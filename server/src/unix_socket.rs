@@ -0,0 +1,81 @@
+use std::future::Future;
+use std::io;
+use std::os::unix::fs::FileTypeExt;
+use std::path::{Path, PathBuf};
+
+use axum::Router;
+use hyper::server::conn::http1;
+use hyper_util::rt::TokioIo;
+use hyper_util::server::graceful::GracefulShutdown;
+use hyper_util::service::TowerToHyperService;
+use tokio::net::{UnixListener, UnixStream};
+
+/// Removes the socket file when the server stops, however it stops
+struct SocketFile(PathBuf);
+
+impl Drop for SocketFile {
+    fn drop(&mut self) {
+        if let Err(e) = std::fs::remove_file(&self.0) {
+            tracing::warn!("Failed to remove socket {}: {}", self.0.display(), e);
+        }
+    }
+}
+
+/// Serves `app` over HTTP/1 on a Unix domain socket until `shutdown` resolves, then waits
+/// for in-flight connections and removes the socket file
+pub async fn serve(path: &Path, app: Router, shutdown: impl Future<Output = ()>) -> io::Result<()> {
+    remove_stale_socket(path).await?;
+    let listener = UnixListener::bind(path)?;
+    let _socket_file = SocketFile(path.to_path_buf());
+    
+    let graceful = GracefulShutdown::new();
+    tokio::pin!(shutdown);
+    loop {
+        let stream = tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => stream,
+                Err(e) => {
+                    tracing::warn!("Failed to accept a Unix socket connection: {}", e);
+                    continue;
+                }
+            },
+            _ = &mut shutdown => break,
+        };
+        let connection = http1::Builder::new()
+            .serve_connection(TokioIo::new(stream), TowerToHyperService::new(app.clone()));
+        let connection = graceful.watch(connection);
+        tokio::spawn(async move {
+            if let Err(e) = connection.await {
+                tracing::debug!("Unix socket connection ended with an error: {}", e);
+            }
+        });
+    }
+    
+    drop(listener);
+    graceful.shutdown().await;
+    Ok(())
+}
+
+/// A socket left behind by a crashed instance is replaced. One that still accepts connections
+/// belongs to a running server, and anything that isn't a socket is never deleted
+async fn remove_stale_socket(path: &Path) -> io::Result<()> {
+    let metadata = match std::fs::symlink_metadata(path) {
+        Ok(metadata) => metadata,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    };
+    if !metadata.file_type().is_socket() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} exists and is not a socket", path.display()),
+        ));
+    }
+    if UnixStream::connect(path).await.is_ok() {
+        return Err(io::Error::new(
+            io::ErrorKind::AddrInUse,
+            format!("Another server is listening on {}", path.display()),
+        ));
+    }
+    tracing::info!("Removing stale socket {}", path.display());
+    std::fs::remove_file(path)
+}
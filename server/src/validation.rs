@@ -0,0 +1,124 @@
+use std::collections::HashSet;
+
+use thiserror::Error;
+
+use crate::db::Pkh;
+
+const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+pub(crate) const MAX_PKH_LEN: usize = 128;
+
+/// One broken wallet invariant; `validate_multisig_invariants` reports all of them at once
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum MultisigViolation {
+    #[error("signer_pkhs must not be empty")]
+    NoSigners,
+
+    #[error("signer_pkhs lists {pkh} more than once")]
+    DuplicateSigner { pkh: String },
+
+    #[error("total_signers is {total_signers} but {listed} signer_pkhs were given")]
+    SignerCountMismatch { total_signers: i32, listed: usize },
+
+    #[error("threshold must be between 1 and total_signers ({total_signers}): {threshold}")]
+    ThresholdOutOfRange { threshold: i32, total_signers: i32 },
+
+    #[error("signer PKH is not valid base58: {pkh:?}")]
+    InvalidPkh { pkh: String },
+
+    #[error("required signer {pkh} is not in signer_pkhs")]
+    RequiredSignerNotListed { pkh: Pkh },
+
+    #[error("required_signers lists {pkh} more than once")]
+    DuplicateRequiredSigner { pkh: Pkh },
+}
+
+/// Non-empty base58 no longer than `max_len`
+pub fn is_base58(value: &str, max_len: usize) -> bool {
+    !value.is_empty()
+        && value.len() <= max_len
+        && value.chars().all(|c| BASE58_ALPHABET.contains(c))
+}
+
+/// Rules every wallet-creating path enforces: at least one signer, no duplicates, the list
+/// matches total_signers, 1 <= threshold <= total_signers, every PKH is base58, and the
+/// required signers are distinct members of the signer list
+pub fn validate_multisig_invariants(
+    threshold: i32,
+    total_signers: i32,
+    signer_pkhs: &[String],
+    required_signers: &[Pkh],
+) -> Result<(), Vec<MultisigViolation>> {
+    let mut violations = Vec::new();
+    if signer_pkhs.is_empty() {
+        violations.push(MultisigViolation::NoSigners);
+    }
+    let mut seen = HashSet::new();
+    for pkh in signer_pkhs {
+        if !is_base58(pkh, MAX_PKH_LEN) {
+            violations.push(MultisigViolation::InvalidPkh { pkh: pkh.clone() });
+        } else if !seen.insert(pkh.as_str()) {
+            violations.push(MultisigViolation::DuplicateSigner { pkh: pkh.clone() });
+        }
+    }
+    if usize::try_from(total_signers).ok() != Some(signer_pkhs.len()) {
+        violations.push(MultisigViolation::SignerCountMismatch { total_signers, listed: signer_pkhs.len() });
+    }
+    if threshold < 1 || threshold > total_signers {
+        violations.push(MultisigViolation::ThresholdOutOfRange { threshold, total_signers });
+    }
+    let mut seen_required = HashSet::new();
+    for pkh in required_signers {
+        if !signer_pkhs.iter().any(|signer| pkh == signer.as_str()) {
+            violations.push(MultisigViolation::RequiredSignerNotListed { pkh: pkh.clone() });
+        } else if !seen_required.insert(pkh) {
+            violations.push(MultisigViolation::DuplicateRequiredSigner { pkh: pkh.clone() });
+        }
+    }
+    if violations.is_empty() { Ok(()) } else { Err(violations) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pkhs(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn accepts_a_consistent_wallet() {
+        assert_eq!(validate_multisig_invariants(2, 3, &pkhs(&["A", "B", "C"]), &[]), Ok(()));
+        assert_eq!(validate_multisig_invariants(1, 1, &pkhs(&["A"]), &[]), Ok(()));
+        let cfo = Pkh::parse("C").unwrap();
+        assert_eq!(validate_multisig_invariants(2, 3, &pkhs(&["A", "B", "C"]), &[cfo]), Ok(()));
+    }
+
+    #[test]
+    fn reports_every_violation() {
+        let violations = validate_multisig_invariants(4, 3, &pkhs(&["A", "A", "0OIl"]), &[]).unwrap_err();
+        assert_eq!(violations, vec![
+            MultisigViolation::DuplicateSigner { pkh: "A".to_string() },
+            MultisigViolation::InvalidPkh { pkh: "0OIl".to_string() },
+            MultisigViolation::ThresholdOutOfRange { threshold: 4, total_signers: 3 },
+        ]);
+
+        let violations = validate_multisig_invariants(0, 0, &[], &[]).unwrap_err();
+        assert_eq!(violations, vec![
+            MultisigViolation::NoSigners,
+            MultisigViolation::ThresholdOutOfRange { threshold: 0, total_signers: 0 },
+        ]);
+
+        let violations = validate_multisig_invariants(1, 3, &pkhs(&["A", ""]), &[]).unwrap_err();
+        assert_eq!(violations, vec![
+            MultisigViolation::InvalidPkh { pkh: String::new() },
+            MultisigViolation::SignerCountMismatch { total_signers: 3, listed: 2 },
+        ]);
+
+        let (a, d) = (Pkh::parse("A").unwrap(), Pkh::parse("D").unwrap());
+        let violations = validate_multisig_invariants(1, 2, &pkhs(&["A", "B"]), &[a.clone(), d.clone(), a.clone()]).unwrap_err();
+        assert_eq!(violations, vec![
+            MultisigViolation::RequiredSignerNotListed { pkh: d },
+            MultisigViolation::DuplicateRequiredSigner { pkh: a },
+        ]);
+    }
+}